@@ -0,0 +1,60 @@
+use std::any::Any;
+
+use crate::NotFoundError;
+
+/// An extension trait for `dyn Any` that returns `NotFoundError<T>` instead of `None` when the value is not of type `T`
+///
+/// Implemented for `dyn Any`, `dyn Any + Send` and `dyn Any + Send + Sync`.
+/// Useful for type-erased plugin and extension maps.
+///
+/// # Examples
+///
+/// ```
+/// use std::any::Any;
+/// use not_found_error::{AnyExt, NotFoundError};
+///
+/// let mut value: Box<dyn Any + Send + Sync> = Box::new(42_i32);
+///
+/// assert_eq!(value.downcast_ref_required::<i32>(), Ok(&42));
+/// assert_eq!(value.downcast_ref_required::<String>(), Err(NotFoundError::new()));
+///
+/// *value.downcast_mut_required::<i32>().unwrap() += 1;
+/// assert_eq!(value.downcast_required::<i32>(), Ok(Box::new(43)));
+/// ```
+pub trait AnyExt {
+    /// Returns a reference to the inner value if it is of type `T`, or an error otherwise.
+    fn downcast_ref_required<T: Any>(&self) -> Result<&T, NotFoundError<T>>;
+
+    /// Returns a mutable reference to the inner value if it is of type `T`, or an error otherwise.
+    fn downcast_mut_required<T: Any>(&mut self) -> Result<&mut T, NotFoundError<T>>;
+
+    /// Downcasts the box to a concrete type, or returns an error otherwise.
+    ///
+    /// Unlike [`Box::downcast`], the original box is dropped on error.
+    fn downcast_required<T: Any>(self: Box<Self>) -> Result<Box<T>, NotFoundError<T>>;
+}
+
+macro_rules! impl_any_ext {
+    ($($ty:ty),+) => {
+        $(
+            impl AnyExt for $ty {
+                #[inline(always)]
+                fn downcast_ref_required<T: Any>(&self) -> Result<&T, NotFoundError<T>> {
+                    self.downcast_ref().ok_or(NotFoundError::new())
+                }
+
+                #[inline(always)]
+                fn downcast_mut_required<T: Any>(&mut self) -> Result<&mut T, NotFoundError<T>> {
+                    self.downcast_mut().ok_or(NotFoundError::new())
+                }
+
+                #[inline(always)]
+                fn downcast_required<T: Any>(self: Box<Self>) -> Result<Box<T>, NotFoundError<T>> {
+                    self.downcast().map_err(|_| NotFoundError::new())
+                }
+            }
+        )+
+    };
+}
+
+impl_any_ext!(dyn Any, dyn Any + Send, dyn Any + Send + Sync);
@@ -0,0 +1,138 @@
+use std::cell::Cell;
+use std::error::Error;
+use std::io;
+
+/// A marker trait for errors that may represent a "not found" case.
+///
+/// Implemented for all errors of this crate (including enums that have a not-found variant), for [`std::io::Error`] with [`std::io::ErrorKind::NotFound`] (or wrapping a "not found" error) and for `dyn Error` (by walking the source chain).
+/// The errors of this crate are recognized behind `dyn Error` too, so they stay "not found" after boxing (enums only in their not-found variants).
+///
+/// # Examples
+///
+/// ```
+/// use std::error::Error;
+/// use std::io;
+/// use not_found_error::{AnyNotFound, FindError, LocateError, NotFoundError};
+///
+/// assert!(NotFoundError::<i32>::new().is_not_found());
+/// assert!(!LocateError::<i32>::Ambiguous { count: 2 }.is_not_found());
+/// assert!(io::Error::from(io::ErrorKind::NotFound).is_not_found());
+///
+/// let errors: [Box<dyn Error + Send + Sync>; 3] = [
+///     Box::new(LocateError::<i32>::NotFound(NotFoundError::new())),
+///     Box::new(FindError::<i32, io::Error>::NotFound(NotFoundError::new())),
+///     Box::new(LocateError::<i32>::Ambiguous { count: 2 }),
+/// ];
+/// assert!(errors[0].is_not_found());
+/// assert!(errors[1].is_not_found());
+/// assert!(!errors[2].is_not_found());
+/// ```
+pub trait AnyNotFound {
+    fn is_not_found(&self) -> bool;
+}
+
+thread_local! {
+    static NOT_FOUND_MARKED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Returns `source` and records that the current [`Error::source`] call was made on a "not found" error of this crate.
+///
+/// All "not found" errors of this crate call it from their `source()` impls (enums from their not-found variants only), so [`AnyNotFound`] for `dyn Error` recognizes them regardless of their type parameters, which can't be recovered by a downcast.
+/// It doesn't change the returned source, so error reporters see the same chain.
+pub(crate) fn mark_not_found<'a>(source: Option<&'a (dyn Error + 'static)>) -> Option<&'a (dyn Error + 'static)> {
+    NOT_FOUND_MARKED.with(|marked| marked.set(true));
+    source
+}
+
+/// Returns the source of `error` and whether `error` is a "not found" error of this crate (see [`mark_not_found`]).
+fn probe_source<'a>(error: &'a (dyn Error + 'static)) -> (Option<&'a (dyn Error + 'static)>, bool) {
+    NOT_FOUND_MARKED.with(|marked| {
+        marked.set(false);
+        let source = error.source();
+        (source, marked.replace(false))
+    })
+}
+
+/// Walks the [`Error::source`] chain, so the errors that wrap a "not found" error (e.g. boxed errors and enums of other crates) are recognized too.
+///
+/// A link of the chain is "not found" if it is one of the "not found" errors of this crate (see [`AnyNotFound`]) or an [`io::Error`] with [`io::ErrorKind::NotFound`] (or wrapping such an error).
+/// Implement [`AnyNotFound`] for your own error types by delegating to this impl.
+///
+/// # Examples
+///
+/// ```
+/// use std::error::Error;
+/// use std::fmt::{Display, Formatter};
+/// use not_found_error::{AnyNotFound, NotFoundError};
+///
+/// # #[derive(Debug)]
+/// # struct User;
+/// #[derive(Debug)]
+/// enum AppError {
+///     User(NotFoundError<User>),
+///     Database,
+/// }
+///
+/// impl Display for AppError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         f.write_str("request failed")
+///     }
+/// }
+///
+/// impl Error for AppError {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+///         match self {
+///             AppError::User(error) => Some(error),
+///             AppError::Database => None,
+///         }
+///     }
+/// }
+///
+/// impl AnyNotFound for AppError {
+///     fn is_not_found(&self) -> bool {
+///         (self as &(dyn Error + 'static)).is_not_found()
+///     }
+/// }
+///
+/// assert!(AppError::User(NotFoundError::new()).is_not_found());
+/// assert!(!AppError::Database.is_not_found());
+///
+/// let error: Box<dyn Error + Send + Sync> = Box::new(NotFoundError::<User>::new());
+/// assert!(error.is_not_found());
+/// ```
+impl AnyNotFound for dyn Error + 'static {
+    fn is_not_found(&self) -> bool {
+        let mut next = Some(self);
+        while let Some(error) = next {
+            if let Some(error) = error.downcast_ref::<io::Error>() {
+                if error.kind() == io::ErrorKind::NotFound || error.get_ref().is_some_and(AnyNotFound::is_not_found) {
+                    return true;
+                }
+            }
+            let (source, is_not_found) = probe_source(error);
+            if is_not_found {
+                return true;
+            }
+            next = source;
+        }
+        false
+    }
+}
+
+impl AnyNotFound for dyn Error + Send + Sync + 'static {
+    fn is_not_found(&self) -> bool {
+        (self as &(dyn Error + 'static)).is_not_found()
+    }
+}
+
+impl<E: AnyNotFound + ?Sized> AnyNotFound for Box<E> {
+    fn is_not_found(&self) -> bool {
+        E::is_not_found(self)
+    }
+}
+
+impl AnyNotFound for io::Error {
+    fn is_not_found(&self) -> bool {
+        (self as &(dyn Error + 'static)).is_not_found()
+    }
+}
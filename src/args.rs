@@ -0,0 +1,77 @@
+//! Helpers for reading command-line arguments with keyed "not found" errors.
+//!
+//! The functions read [`std::env::args_os`]; the `_from` variants accept any iterator of arguments, which is useful for testing.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::ffi::OsString;
+//! use not_found_error::args;
+//!
+//! let argv = || ["tool", "input.txt", "--config", "dev.toml"].map(OsString::from);
+//!
+//! assert_eq!(args::nth_required_from(argv(), 1).unwrap(), "input.txt");
+//! assert_eq!(args::flag_value_required_from(argv(), "--config").unwrap(), "dev.toml");
+//! assert_eq!(args::flag_value_required_from(argv(), "--output").unwrap_err().to_string(), "std::ffi::os_str::OsString not found by key \"--output\"");
+//! ```
+
+use std::ffi::OsString;
+
+use crate::NotFoundByKey;
+
+/// Returns the `n`-th command-line argument (the program name is at index 0), or an error that contains the index.
+pub fn nth_required(n: usize) -> Result<OsString, NotFoundByKey<OsString, usize>> {
+    nth_required_from(std::env::args_os(), n)
+}
+
+/// Returns the `n`-th argument of `args`, or an error that contains the index.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{args, NotFoundByKey};
+///
+/// assert_eq!(args::nth_required_from(["tool", "input.txt"], 1), Ok("input.txt"));
+/// assert_eq!(args::nth_required_from(["tool"], 1), Err(NotFoundByKey::new(1)));
+/// ```
+pub fn nth_required_from<A>(args: impl IntoIterator<Item = A>, n: usize) -> Result<A, NotFoundByKey<A, usize>> {
+    args.into_iter().nth(n).ok_or(NotFoundByKey::new(n))
+}
+
+/// Returns the value of the command-line flag (either `--flag value` or `--flag=value`), or an error that contains the flag.
+pub fn flag_value_required(flag: &str) -> Result<OsString, NotFoundByKey<OsString, String>> {
+    flag_value_required_from(std::env::args_os(), flag)
+}
+
+/// Returns the value of the flag in `args` (either `--flag value` or `--flag=value`), or an error that contains the flag.
+///
+/// The `--flag=value` form is recognized only if the argument is valid unicode.
+/// A flag without a value (e.g. at the end of the arguments) is treated as missing.
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::OsString;
+/// use not_found_error::{args, NotFoundByKey};
+///
+/// let argv = |args: &[&str]| args.iter().map(OsString::from).collect::<Vec<_>>();
+///
+/// assert_eq!(args::flag_value_required_from(argv(&["tool", "--config", "a.toml"]), "--config").unwrap(), "a.toml");
+/// assert_eq!(args::flag_value_required_from(argv(&["tool", "--config=b.toml"]), "--config").unwrap(), "b.toml");
+/// assert_eq!(args::flag_value_required_from(argv(&["tool", "--config"]), "--config"), Err(NotFoundByKey::new("--config".to_string())));
+/// ```
+pub fn flag_value_required_from(args: impl IntoIterator<Item = OsString>, flag: &str) -> Result<OsString, NotFoundByKey<OsString, String>> {
+    let prefix = format!("{flag}=");
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == flag {
+            return args
+                .next()
+                .ok_or_else(|| NotFoundByKey::new(flag.to_string()));
+        }
+        if let Some(value) = arg.to_str().and_then(|arg| arg.strip_prefix(&prefix)) {
+            return Ok(value.into());
+        }
+    }
+    Err(NotFoundByKey::new(flag.to_string()))
+}
@@ -0,0 +1,38 @@
+use arrayvec::ArrayVec;
+
+use crate::{NotFoundByKey, NotFoundError, VecExt};
+
+/// Requires the `arrayvec` feature.
+///
+/// The slice accessors of [`SliceRequire`](crate::SliceRequire) are available through `Deref<Target = [T]>`.
+///
+/// ```
+/// use arrayvec::ArrayVec;
+/// use not_found_error::{NotFoundByKey, NotFoundError, SliceRequire, VecExt};
+///
+/// let mut samples = ArrayVec::<u8, 4>::from_iter([1, 2]);
+///
+/// assert_eq!(samples.last_required(), Ok(&2));
+/// assert_eq!(samples.get_required(2), Err(NotFoundByKey::new(2)));
+/// assert_eq!(samples.swap_remove_required(0), Ok(1));
+/// assert_eq!(samples.pop_required(), Ok(2));
+/// assert_eq!(samples.pop_required(), Err(NotFoundError::new()));
+/// ```
+impl<T, const CAP: usize> VecExt for ArrayVec<T, CAP> {
+    type T = T;
+
+    #[inline(always)]
+    fn pop_required(&mut self) -> Result<T, NotFoundError<T>> {
+        self.pop().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn remove_required(&mut self, index: usize) -> Result<T, NotFoundByKey<T, usize>> {
+        self.pop_at(index).ok_or(NotFoundByKey::new(index))
+    }
+
+    #[inline(always)]
+    fn swap_remove_required(&mut self, index: usize) -> Result<T, NotFoundByKey<T, usize>> {
+        self.swap_pop(index).ok_or(NotFoundByKey::new(index))
+    }
+}
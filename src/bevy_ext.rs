@@ -0,0 +1,163 @@
+use bevy_asset::{Asset, AssetId, AssetMut, Assets};
+use bevy_ecs::component::{Component, Mutable};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::{QueryData, QueryFilter, QuerySingleError, ROQueryItem};
+use bevy_ecs::system::Query;
+use bevy_ecs::world::{Mut, World};
+
+use crate::{LocateError, NotFoundByKey, NotFoundError};
+
+/// An extension trait for the bevy [`World`] that returns keyed errors instead of `None` (feature `bevy`)
+///
+/// # Examples
+///
+/// ```
+/// use bevy_ecs::component::Component;
+/// use bevy_ecs::world::World;
+/// use not_found_error::{BevyWorldExt, NotFoundByKey};
+///
+/// #[derive(Component, PartialEq, Debug)]
+/// struct Health(u32);
+///
+/// #[derive(Component, PartialEq, Debug)]
+/// struct Shield;
+///
+/// let mut world = World::new();
+/// let player = world.spawn(Health(100)).id();
+///
+/// world.get_mut_required::<Health>(player).unwrap().0 -= 10;
+/// assert_eq!(world.get_required::<Health>(player), Ok(&Health(90)));
+/// assert_eq!(world.get_required::<Shield>(player).unwrap_err(), NotFoundByKey::new(player));
+/// ```
+pub trait BevyWorldExt {
+    /// Returns the component `C` of `entity`, or an error that records the entity.
+    fn get_required<C: Component>(&self, entity: Entity) -> Result<&C, NotFoundByKey<C, Entity>>;
+
+    /// Returns the mutable component `C` of `entity`, or an error that records the entity.
+    fn get_mut_required<C: Component<Mutability = Mutable>>(&mut self, entity: Entity) -> Result<Mut<'_, C>, NotFoundByKey<C, Entity>>;
+}
+
+impl BevyWorldExt for World {
+    #[inline(always)]
+    fn get_required<C: Component>(&self, entity: Entity) -> Result<&C, NotFoundByKey<C, Entity>> {
+        self.get(entity).ok_or(NotFoundByKey::new(entity))
+    }
+
+    #[inline(always)]
+    fn get_mut_required<C: Component<Mutability = Mutable>>(&mut self, entity: Entity) -> Result<Mut<'_, C>, NotFoundByKey<C, Entity>> {
+        self.get_mut(entity).ok_or(NotFoundByKey::new(entity))
+    }
+}
+
+/// An extension trait for the bevy [`Query`] that returns this crate's errors (feature `bevy`)
+///
+/// The errors are labeled by the query data type `D` (e.g. `&Health`).
+///
+/// # Examples
+///
+/// ```
+/// use bevy_ecs::component::Component;
+/// use bevy_ecs::world::World;
+/// use not_found_error::{LocateError, NotFoundByKey, QueryExt};
+///
+/// #[derive(Component, PartialEq, Debug)]
+/// struct Health(u32);
+///
+/// let mut world = World::new();
+/// let player = world.spawn(Health(100)).id();
+/// let rock = world.spawn_empty().id();
+///
+/// let mut state = world.query::<&Health>();
+/// let query = state.query(&world);
+/// assert_eq!(query.get_required(player), Ok(&Health(100)));
+/// assert_eq!(query.get_required(rock), Err(NotFoundByKey::new(rock)));
+/// assert_eq!(query.single_required(), Ok(&Health(100)));
+///
+/// world.spawn(Health(50));
+/// let mut state = world.query::<&Health>();
+/// assert_eq!(state.query(&world).single_required(), Err(LocateError::Ambiguous { count: 2 }));
+/// ```
+pub trait QueryExt<'s> {
+    type D: QueryData;
+
+    /// Returns the read-only query item for `entity`, or an error that records the entity.
+    fn get_required(&self, entity: Entity) -> Result<ROQueryItem<'_, 's, Self::D>, NotFoundByKey<Self::D, Entity>>;
+
+    /// Returns the query item for `entity`, or an error that records the entity.
+    fn get_mut_required(&mut self, entity: Entity) -> Result<<Self::D as QueryData>::Item<'_, 's>, NotFoundByKey<Self::D, Entity>>;
+
+    /// Returns the read-only query item if exactly one entity matches the query.
+    ///
+    /// Returns [`LocateError::NotFound`] if no entity matches, or [`LocateError::Ambiguous`] with the number of matches otherwise.
+    fn single_required(&self) -> Result<ROQueryItem<'_, 's, Self::D>, LocateError<Self::D>>;
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter> QueryExt<'s> for Query<'w, 's, D, F> {
+    type D = D;
+
+    #[inline(always)]
+    fn get_required(&self, entity: Entity) -> Result<ROQueryItem<'_, 's, D>, NotFoundByKey<D, Entity>> {
+        self.get(entity)
+            .map_err(|_error| NotFoundByKey::new(entity))
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self, entity: Entity) -> Result<D::Item<'_, 's>, NotFoundByKey<D, Entity>> {
+        self.get_mut(entity)
+            .map_err(|_error| NotFoundByKey::new(entity))
+    }
+
+    fn single_required(&self) -> Result<ROQueryItem<'_, 's, D>, LocateError<D>> {
+        match self.single() {
+            Ok(item) => Ok(item),
+            Err(QuerySingleError::NoEntities(_)) => Err(LocateError::NotFound(NotFoundError::new())),
+            Err(QuerySingleError::MultipleEntities(_)) => Err(LocateError::Ambiguous {
+                count: self.iter().count(),
+            }),
+        }
+    }
+}
+
+/// An extension trait for bevy [`Assets`] that returns keyed errors instead of `None` (feature `bevy`)
+///
+/// Covers the "asset not loaded yet" failure mode: the error records the id of the handle.
+///
+/// # Examples
+///
+/// ```
+/// use bevy_asset::{Asset, Assets};
+/// use bevy_reflect::TypePath;
+/// use not_found_error::{AssetsExt, NotFoundByKey};
+///
+/// #[derive(Asset, TypePath, PartialEq, Debug)]
+/// struct Level(u32);
+///
+/// let mut levels = Assets::<Level>::default();
+/// let handle = levels.add(Level(1));
+/// assert_eq!(levels.get_required(&handle), Ok(&Level(1)));
+///
+/// levels.get_mut_required(&handle).unwrap().0 = 2;
+/// levels.remove(&handle);
+/// assert_eq!(levels.get_required(&handle), Err(NotFoundByKey::new(handle.id())));
+/// ```
+pub trait AssetsExt<A: Asset> {
+    /// Returns the asset for `id` (e.g. a `Handle<A>`), or an error that records the asset id.
+    fn get_required(&self, id: impl Into<AssetId<A>>) -> Result<&A, NotFoundByKey<A, AssetId<A>>>;
+
+    /// Returns the mutable asset for `id` (e.g. a `Handle<A>`), or an error that records the asset id.
+    fn get_mut_required(&mut self, id: impl Into<AssetId<A>>) -> Result<AssetMut<'_, A>, NotFoundByKey<A, AssetId<A>>>;
+}
+
+impl<A: Asset> AssetsExt<A> for Assets<A> {
+    #[inline(always)]
+    fn get_required(&self, id: impl Into<AssetId<A>>) -> Result<&A, NotFoundByKey<A, AssetId<A>>> {
+        let id = id.into();
+        self.get(id).ok_or(NotFoundByKey::new(id))
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self, id: impl Into<AssetId<A>>) -> Result<AssetMut<'_, A>, NotFoundByKey<A, AssetId<A>>> {
+        let id = id.into();
+        self.get_mut(id).ok_or(NotFoundByKey::new(id))
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::BinaryHeap;
+
+use crate::NotFoundError;
+
+/// An extension trait for [`BinaryHeap`] that returns `NotFoundError<T>` instead of `None` when the heap is empty
+///
+/// Useful for schedulers and priority queues that treat emptiness as an error.
+pub trait BinaryHeapExt {
+    type T;
+
+    /// Returns the greatest element or an error if the heap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BinaryHeap;
+    /// use not_found_error::{BinaryHeapExt, NotFoundError};
+    ///
+    /// assert_eq!(BinaryHeap::from([1, 3, 2]).peek_required(), Ok(&3));
+    /// assert_eq!(BinaryHeap::<i32>::new().peek_required(), Err(NotFoundError::new()));
+    /// ```
+    fn peek_required(&self) -> Result<&Self::T, NotFoundError<Self::T>>;
+
+    /// Removes the greatest element and returns it, or returns an error if the heap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BinaryHeap;
+    /// use not_found_error::{BinaryHeapExt, NotFoundError};
+    ///
+    /// let mut tasks = BinaryHeap::from([1]);
+    /// assert_eq!(tasks.pop_required(), Ok(1));
+    /// assert_eq!(tasks.pop_required(), Err(NotFoundError::new()));
+    /// ```
+    fn pop_required(&mut self) -> Result<Self::T, NotFoundError<Self::T>>;
+}
+
+impl<T: Ord> BinaryHeapExt for BinaryHeap<T> {
+    type T = T;
+
+    #[inline(always)]
+    fn peek_required(&self) -> Result<&T, NotFoundError<T>> {
+        self.peek().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn pop_required(&mut self) -> Result<T, NotFoundError<T>> {
+        self.pop().ok_or(NotFoundError::new())
+    }
+}
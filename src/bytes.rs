@@ -0,0 +1,94 @@
+//! Helpers for searching byte patterns in slices and readers with keyed "not found" errors.
+//!
+//! Useful for binary format parsing, where missing magic bytes are an error. The search is accelerated by `memchr` behind the `memchr` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use not_found_error::bytes;
+//!
+//! let file = b"\x00\x00PK\x03\x04archive";
+//! assert_eq!(bytes::find_subslice_required(file, b"PK\x03\x04"), Ok(2));
+//! ```
+
+use std::io::{self, Read};
+
+use crate::{FindByKeyError, NotFoundByKey};
+
+/// A marker type for a byte pattern searched with the functions of the [`bytes`](self) module
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default, Debug)]
+pub struct Pattern;
+
+/// A type alias for the error returned by [`find_in_reader_required`]: either an IO error of the reader or a `NotFoundByKey` that records the pattern
+pub type FindInReaderError = FindByKeyError<Pattern, Vec<u8>, io::Error>;
+
+/// Returns the index of the first occurrence of `needle` in `haystack`, or an error that records `needle`.
+///
+/// An empty `needle` is found at index 0.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::bytes::{self, Pattern};
+/// use not_found_error::NotFoundByKey;
+///
+/// assert_eq!(bytes::find_subslice_required(b"GIF89a", b"89a"), Ok(3));
+/// assert_eq!(bytes::find_subslice_required(b"GIF89a", b"PNG"), Err(NotFoundByKey::<Pattern, _>::new(b"PNG".to_vec())));
+/// ```
+pub fn find_subslice_required(haystack: &[u8], needle: &[u8]) -> Result<usize, NotFoundByKey<Pattern, Vec<u8>>> {
+    find_subslice(haystack, needle).ok_or_else(|| NotFoundByKey::new(needle.to_vec()))
+}
+
+/// Returns the stream position of the first occurrence of `needle` in the reader, or an error that records `needle`.
+///
+/// Stops reading right after the chunk that contains the match, and keeps at most `needle.len() - 1` bytes of the previous chunk in memory.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::bytes::{self, FindInReaderError};
+///
+/// let stream = [vec![0; 10_000], b"IEND".to_vec()].concat();
+/// assert_eq!(bytes::find_in_reader_required(stream.as_slice(), b"IEND").unwrap(), 10_000);
+///
+/// let error = bytes::find_in_reader_required(&b"IHDR"[..], b"IEND").unwrap_err();
+/// assert!(matches!(error, FindInReaderError::NotFound(_)));
+/// ```
+pub fn find_in_reader_required(mut reader: impl Read, needle: &[u8]) -> Result<u64, FindInReaderError> {
+    let keep = needle.len().saturating_sub(1);
+    let mut window = Vec::new();
+    let mut offset = 0u64;
+    let mut chunk = [0; 8192];
+    loop {
+        if let Some(index) = find_subslice(&window, needle) {
+            return Ok(offset + index as u64);
+        }
+        if window.len() > keep {
+            let drained = window.len() - keep;
+            window.drain(..drained);
+            offset += drained as u64;
+        }
+        let read = match reader.read(&mut chunk) {
+            Ok(0) => return Err(FindByKeyError::NotFound(NotFoundByKey::new(needle.to_vec()))),
+            Ok(read) => read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(FindByKeyError::Other(error)),
+        };
+        window.extend_from_slice(&chunk[..read]);
+    }
+}
+
+#[cfg(feature = "memchr")]
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    memchr::memmem::find(haystack, needle)
+}
+
+#[cfg(not(feature = "memchr"))]
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
@@ -0,0 +1,48 @@
+use std::any::Any;
+
+use clap::parser::ValuesRef;
+use clap::ArgMatches;
+
+use crate::NotFoundByKey;
+
+/// An extension trait for clap [`ArgMatches`] that returns keyed errors with the argument id instead of `None` (feature `clap`)
+///
+/// Like [`ArgMatches::get_one`], the methods panic if `id` is not a valid argument or `T` doesn't match its value type.
+///
+/// # Examples
+///
+/// ```
+/// use clap::{Arg, Command};
+/// use not_found_error::{ArgMatchesExt, NotFoundByKey};
+///
+/// let command = Command::new("deploy").arg(Arg::new("target").long("target")).arg(Arg::new("tag").long("tag").num_args(1..));
+///
+/// let matches = command.clone().get_matches_from(["deploy", "--target", "prod", "--tag", "a", "b"]);
+/// assert_eq!(matches.get_one_required::<String>("target").unwrap(), "prod");
+/// assert_eq!(matches.get_many_required::<String>("tag").unwrap().collect::<Vec<_>>(), ["a", "b"]);
+///
+/// let matches = command.get_matches_from(["deploy"]);
+/// assert_eq!(matches.get_one_required::<String>("target"), Err(NotFoundByKey::new("target".to_string())));
+/// assert!(matches.get_many_required::<String>("tag").is_err());
+/// ```
+pub trait ArgMatchesExt {
+    /// Returns the value of the argument `id`, or an error that records `id`.
+    fn get_one_required<T: Any + Clone + Send + Sync + 'static>(&self, id: &str) -> Result<&T, NotFoundByKey<T, String>>;
+
+    /// Returns the values of the argument `id`, or an error that records `id`.
+    fn get_many_required<T: Any + Clone + Send + Sync + 'static>(&self, id: &str) -> Result<ValuesRef<'_, T>, NotFoundByKey<T, String>>;
+}
+
+impl ArgMatchesExt for ArgMatches {
+    #[inline(always)]
+    fn get_one_required<T: Any + Clone + Send + Sync + 'static>(&self, id: &str) -> Result<&T, NotFoundByKey<T, String>> {
+        self.get_one::<T>(id)
+            .ok_or_else(|| NotFoundByKey::new(id.to_string()))
+    }
+
+    #[inline(always)]
+    fn get_many_required<T: Any + Clone + Send + Sync + 'static>(&self, id: &str) -> Result<ValuesRef<'_, T>, NotFoundByKey<T, String>> {
+        self.get_many::<T>(id)
+            .ok_or_else(|| NotFoundByKey::new(id.to_string()))
+    }
+}
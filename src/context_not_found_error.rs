@@ -0,0 +1,88 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::{mark_not_found, AnyNotFound, NotFoundError};
+
+/// Represents an error indicating that a value was not found, with a human-readable context.
+///
+/// Usually created with [`RequireWith::require_with`].
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::ContextNotFoundError;
+///
+/// let error = ContextNotFoundError::<i32>::new("loading user 42");
+/// assert_eq!(error.to_string(), "loading user 42: i32 not found");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Debug)]
+pub struct ContextNotFoundError<T> {
+    pub context: String,
+    pub phantom: PhantomData<T>,
+}
+
+impl<T> ContextNotFoundError<T> {
+    /// Creates a new `ContextNotFoundError` with the given context.
+    pub fn new(context: impl Into<String>) -> Self {
+        Self {
+            context: context.into(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Display for ContextNotFoundError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} not found", self.context, type_name::<T>())
+    }
+}
+
+impl<T: Debug> Error for ContextNotFoundError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        mark_not_found(None)
+    }
+}
+
+impl<T> From<ContextNotFoundError<T>> for NotFoundError<T> {
+    fn from(_error: ContextNotFoundError<T>) -> Self {
+        NotFoundError::new()
+    }
+}
+
+impl<T> AnyNotFound for ContextNotFoundError<T> {
+    fn is_not_found(&self) -> bool {
+        true
+    }
+}
+
+/// An extension trait for `Option<T>` to convert it to `Result<T, ContextNotFoundError<T>>`
+///
+/// The context closure is evaluated only if the option is `None`, so hot paths don't pay for formatting.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::RequireWith;
+///
+/// let id = 42;
+/// assert_eq!(Some(1).require_with(|| format!("loading user {id}")), Ok(1));
+///
+/// let error = None::<i32>.require_with(|| format!("loading user {id}")).unwrap_err();
+/// assert_eq!(error.context, "loading user 42");
+/// ```
+pub trait RequireWith {
+    type T;
+
+    fn require_with<C: Into<String>>(self, context: impl FnOnce() -> C) -> Result<Self::T, ContextNotFoundError<Self::T>>;
+}
+
+impl<T> RequireWith for Option<T> {
+    type T = T;
+
+    #[inline(always)]
+    fn require_with<C: Into<String>>(self, context: impl FnOnce() -> C) -> Result<T, ContextNotFoundError<T>> {
+        self.ok_or_else(|| ContextNotFoundError::new(context()))
+    }
+}
@@ -0,0 +1,71 @@
+use std::borrow::Borrow;
+use std::hash::{BuildHasher, Hash};
+
+use dashmap::mapref::one::{Ref, RefMut};
+use dashmap::DashMap;
+
+use crate::NotFoundByKey;
+
+/// An extension trait for [`DashMap`] that returns keyed errors instead of `None` (feature `dashmap`)
+///
+/// The guard types of dashmap are preserved, so the shard lock is held for as long as the returned reference is alive.
+///
+/// # Examples
+///
+/// ```
+/// use dashmap::DashMap;
+/// use not_found_error::{DashMapExt, NotFoundByKey};
+///
+/// let cache = DashMap::new();
+/// cache.insert("alice".to_string(), 1);
+///
+/// assert_eq!(*cache.get_required("alice").unwrap(), 1);
+/// *cache.get_mut_required("alice").unwrap() += 1;
+/// assert_eq!(cache.remove_required("alice"), Ok(("alice".to_string(), 2)));
+/// assert_eq!(cache.get_required("alice").unwrap_err(), NotFoundByKey::new("alice".to_string()));
+/// ```
+pub trait DashMapExt<K, V> {
+    /// Returns a read guard for the value of `key`, or an error that contains an owned copy of the key.
+    fn get_required<Q: ?Sized + Hash + Eq + ToOwned>(&self, key: &Q) -> Result<Ref<'_, K, V>, NotFoundByKey<V, Q::Owned>>
+    where
+        K: Borrow<Q>;
+
+    /// Returns a write guard for the value of `key`, or an error that contains an owned copy of the key.
+    fn get_mut_required<Q: ?Sized + Hash + Eq + ToOwned>(&self, key: &Q) -> Result<RefMut<'_, K, V>, NotFoundByKey<V, Q::Owned>>
+    where
+        K: Borrow<Q>;
+
+    /// Removes the entry for `key` and returns it, or returns an error that contains an owned copy of the key.
+    fn remove_required<Q: ?Sized + Hash + Eq + ToOwned>(&self, key: &Q) -> Result<(K, V), NotFoundByKey<V, Q::Owned>>
+    where
+        K: Borrow<Q>;
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> DashMapExt<K, V> for DashMap<K, V, S> {
+    #[inline(always)]
+    fn get_required<Q: ?Sized + Hash + Eq + ToOwned>(&self, key: &Q) -> Result<Ref<'_, K, V>, NotFoundByKey<V, Q::Owned>>
+    where
+        K: Borrow<Q>,
+    {
+        self.get(key)
+            .ok_or_else(|| NotFoundByKey::new(key.to_owned()))
+    }
+
+    #[inline(always)]
+    fn get_mut_required<Q: ?Sized + Hash + Eq + ToOwned>(&self, key: &Q) -> Result<RefMut<'_, K, V>, NotFoundByKey<V, Q::Owned>>
+    where
+        K: Borrow<Q>,
+    {
+        self.get_mut(key)
+            .ok_or_else(|| NotFoundByKey::new(key.to_owned()))
+    }
+
+    #[inline(always)]
+    fn remove_required<Q: ?Sized + Hash + Eq + ToOwned>(&self, key: &Q) -> Result<(K, V), NotFoundByKey<V, Q::Owned>>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove(key)
+            .ok_or_else(|| NotFoundByKey::new(key.to_owned()))
+    }
+}
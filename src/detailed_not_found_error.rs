@@ -0,0 +1,135 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::{mark_not_found, AnyNotFound, DynNotFoundError, NotFoundError};
+
+/// Represents an error indicating that a value was not found, with the lookup keys and the container description.
+///
+/// Usually created with the [`NotFound`] builder.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{NotFound, NotFoundError};
+///
+/// # struct User;
+/// let error = NotFound::<User>::by("id", 42).and("active", true).in_("users table").build();
+/// assert!(error.to_string().ends_with("User not found by id = 42, active = true in users table"));
+///
+/// // Convert into a plain error when detail isn't needed
+/// let error: NotFoundError<User> = error.into();
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Debug)]
+pub struct DetailedNotFoundError<T> {
+    /// Key names with the `Debug` representations of their values
+    pub keys: Vec<(&'static str, String)>,
+    /// The description of the place where the value was looked up
+    pub container: Option<String>,
+    pub phantom: PhantomData<T>,
+}
+
+impl<T> Display for DetailedNotFoundError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} not found", type_name::<T>())?;
+        for (index, (name, value)) in self.keys.iter().enumerate() {
+            let separator = if index == 0 { " by " } else { ", " };
+            write!(f, "{separator}{name} = {value}")?;
+        }
+        if let Some(container) = &self.container {
+            write!(f, " in {container}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Debug> Error for DetailedNotFoundError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        mark_not_found(None)
+    }
+}
+
+impl<T> AnyNotFound for DetailedNotFoundError<T> {
+    fn is_not_found(&self) -> bool {
+        true
+    }
+}
+
+impl<T> From<DetailedNotFoundError<T>> for NotFoundError<T> {
+    fn from(_error: DetailedNotFoundError<T>) -> Self {
+        NotFoundError::new()
+    }
+}
+
+impl<T> From<DetailedNotFoundError<T>> for DynNotFoundError {
+    fn from(error: DetailedNotFoundError<T>) -> Self {
+        let mut dyn_error = DynNotFoundError::new::<T>();
+        if !error.keys.is_empty() {
+            let key = error
+                .keys
+                .iter()
+                .map(|(name, value)| format!("{name} = {value}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            dyn_error.key = Some(key);
+        }
+        dyn_error
+    }
+}
+
+/// A builder for [`DetailedNotFoundError`]
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::NotFound;
+///
+/// let error = NotFound::<String>::by("name", "alice").build();
+/// assert_eq!(error.to_string(), "alloc::string::String not found by name = \"alice\"");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Debug)]
+pub struct NotFound<T> {
+    error: DetailedNotFoundError<T>,
+}
+
+impl<T> NotFound<T> {
+    /// Creates a builder without keys.
+    pub fn new() -> Self {
+        Self {
+            error: DetailedNotFoundError {
+                keys: Vec::new(),
+                container: None,
+                phantom: PhantomData,
+            },
+        }
+    }
+
+    /// Creates a builder with a single key.
+    pub fn by(name: &'static str, value: impl Debug) -> Self {
+        Self::new().and(name, value)
+    }
+
+    /// Adds another key.
+    pub fn and(mut self, name: &'static str, value: impl Debug) -> Self {
+        self.error.keys.push((name, format!("{value:?}")));
+        self
+    }
+
+    /// Sets the description of the place where the value was looked up.
+    pub fn in_(mut self, container: impl Into<String>) -> Self {
+        self.error.container = Some(container.into());
+        self
+    }
+
+    /// Returns the error.
+    pub fn build(self) -> DetailedNotFoundError<T> {
+        self.error
+    }
+}
+
+impl<T> Default for NotFound<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
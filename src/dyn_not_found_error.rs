@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::{mark_not_found, AnyNotFound, NotFoundError};
+
+/// A type-erased version of [`NotFoundError`] that records the name of the missing type and an optional key.
+///
+/// Useful when errors about different types need to be stored together (e.g. in a [`NotFoundReport`](crate::report::NotFoundReport)).
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{DynNotFoundError, NotFoundError};
+///
+/// let error = DynNotFoundError::from(NotFoundError::<i32>::new());
+/// assert_eq!(error.to_string(), "i32 not found");
+///
+/// let error = DynNotFoundError::with_key::<String>(&42);
+/// assert_eq!(error.to_string(), "alloc::string::String not found by key 42");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Debug)]
+pub struct DynNotFoundError {
+    /// The name of the missing type (usually returned by [`type_name`](std::any::type_name))
+    pub type_name: Cow<'static, str>,
+    /// The `Debug` representation of the key that was used for the lookup
+    pub key: Option<String>,
+}
+
+impl DynNotFoundError {
+    /// Creates a new `DynNotFoundError` for type `T` without a key.
+    pub fn new<T: ?Sized>() -> Self {
+        Self {
+            type_name: Cow::Borrowed(std::any::type_name::<T>()),
+            key: None,
+        }
+    }
+
+    /// Creates a new `DynNotFoundError` for a type with the given name (e.g. received over the wire).
+    pub fn named(type_name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            type_name: type_name.into(),
+            key: None,
+        }
+    }
+
+    /// Creates a new `DynNotFoundError` for type `T` with a key.
+    pub fn with_key<T: ?Sized>(key: &impl Debug) -> Self {
+        Self {
+            type_name: Cow::Borrowed(std::any::type_name::<T>()),
+            key: Some(format!("{key:?}")),
+        }
+    }
+}
+
+impl<T: ?Sized> From<NotFoundError<T>> for DynNotFoundError {
+    fn from(_error: NotFoundError<T>) -> Self {
+        Self::new::<T>()
+    }
+}
+
+impl Display for DynNotFoundError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.key {
+            None => write!(f, "{} not found", self.type_name),
+            Some(key) => write!(f, "{} not found by key {}", self.type_name, key),
+        }
+    }
+}
+
+impl Error for DynNotFoundError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        mark_not_found(None)
+    }
+}
+
+impl AnyNotFound for DynNotFoundError {
+    fn is_not_found(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::{btree_map, hash_map};
+
+use crate::NotFoundByKey;
+
+/// An extension trait for the entries of the std maps that treats a vacant entry as an error
+///
+/// Implemented for [`hash_map::Entry`] and [`btree_map::Entry`].
+/// Useful for flows that must only update existing entries.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use not_found_error::{EntryExt, NotFoundByKey};
+///
+/// let mut stock = HashMap::from([("apple", 3)]);
+///
+/// *stock.entry("apple").occupied_or_not_found().unwrap().get_mut() -= 1;
+/// assert_eq!(stock["apple"], 2);
+///
+/// let error = stock.entry("pear").occupied_or_not_found().unwrap_err();
+/// assert_eq!(error, NotFoundByKey::new("pear"));
+/// assert!(!stock.contains_key("pear"));
+/// ```
+pub trait EntryExt {
+    type K;
+    type V;
+    type Occupied;
+
+    /// Returns the occupied entry, or an error that contains the key if the entry is vacant.
+    fn occupied_or_not_found(self) -> Result<Self::Occupied, NotFoundByKey<Self::V, Self::K>>;
+}
+
+impl<'a, K, V> EntryExt for hash_map::Entry<'a, K, V> {
+    type K = K;
+    type V = V;
+    type Occupied = hash_map::OccupiedEntry<'a, K, V>;
+
+    #[inline(always)]
+    fn occupied_or_not_found(self) -> Result<hash_map::OccupiedEntry<'a, K, V>, NotFoundByKey<V, K>> {
+        match self {
+            hash_map::Entry::Occupied(entry) => Ok(entry),
+            hash_map::Entry::Vacant(entry) => Err(NotFoundByKey::new(entry.into_key())),
+        }
+    }
+}
+
+impl<'a, K: Ord, V> EntryExt for btree_map::Entry<'a, K, V> {
+    type K = K;
+    type V = V;
+    type Occupied = btree_map::OccupiedEntry<'a, K, V>;
+
+    #[inline(always)]
+    fn occupied_or_not_found(self) -> Result<btree_map::OccupiedEntry<'a, K, V>, NotFoundByKey<V, K>> {
+        match self {
+            btree_map::Entry::Occupied(entry) => Ok(entry),
+            btree_map::Entry::Vacant(entry) => Err(NotFoundByKey::new(entry.into_key())),
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Helpers for reading environment variables with typed "not found" errors.
+//!
+//! The marker type labels the error, so `DATABASE_URL not found` is distinguishable from other missing variables at the type level.
+//!
+//! # Examples
+//!
+//! ```
+//! use not_found_error::env::{self, EnvError};
+//! use not_found_error::NotFoundByKey;
+//!
+//! #[derive(PartialEq, Debug)]
+//! struct DatabaseUrl;
+//!
+//! let error = env::required::<DatabaseUrl>("NOT_FOUND_ERROR_DOCTEST_UNSET").unwrap_err();
+//! assert_eq!(error, EnvError::NotFound(NotFoundByKey::new("NOT_FOUND_ERROR_DOCTEST_UNSET".to_string())));
+//! ```
+
+use std::convert::Infallible;
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt::{Debug, Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::{mark_not_found, AnyNotFound, NotFoundByKey};
+
+/// Represents an error returned by the functions of the [`env`](self) module.
+///
+/// The `E` parameter is the parse error of [`required_parsed`].
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum EnvError<M, E = Infallible> {
+    /// The variable is not set
+    NotFound(NotFoundByKey<M, String>),
+    /// The variable is set, but its value is not valid unicode
+    NotUnicode { key: String, value: OsString },
+    /// The variable is set, but its value could not be parsed
+    Parse { key: String, source: E },
+}
+
+impl<M, E: Display> Display for EnvError<M, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnvError::NotFound(error) => Display::fmt(error, f),
+            EnvError::NotUnicode {
+                key,
+                value,
+            } => write!(f, "environment variable {key:?} is not valid unicode: {value:?}"),
+            EnvError::Parse {
+                key,
+                source,
+            } => write!(f, "environment variable {key:?} could not be parsed: {source}"),
+        }
+    }
+}
+
+impl<M: Debug, E: Error + 'static> Error for EnvError<M, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EnvError::Parse {
+                source,
+                ..
+            } => Some(source),
+            EnvError::NotFound(_) => mark_not_found(None),
+            EnvError::NotUnicode {
+                ..
+            } => None,
+        }
+    }
+}
+
+impl<M, E> AnyNotFound for EnvError<M, E> {
+    fn is_not_found(&self) -> bool {
+        matches!(self, EnvError::NotFound(_))
+    }
+}
+
+/// Returns the value of the environment variable `key`.
+///
+/// Unlike [`std::env::var`], the error distinguishes an unset variable (labeled by `M`) from a non-unicode value.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::env;
+///
+/// # #[derive(Debug)]
+/// struct DatabaseUrl;
+///
+/// # std::env::set_var("NOT_FOUND_ERROR_DOCTEST_URL", "postgres://localhost");
+/// assert_eq!(env::required::<DatabaseUrl>("NOT_FOUND_ERROR_DOCTEST_URL").unwrap(), "postgres://localhost");
+///
+/// let error = env::required::<DatabaseUrl>("NOT_FOUND_ERROR_DOCTEST_MISSING").unwrap_err();
+/// assert!(error.to_string().ends_with("DatabaseUrl not found by key \"NOT_FOUND_ERROR_DOCTEST_MISSING\""));
+/// ```
+pub fn required<M>(key: &str) -> Result<String, EnvError<M>> {
+    match std::env::var_os(key) {
+        None => Err(EnvError::NotFound(NotFoundByKey::new(key.to_string()))),
+        Some(value) => value.into_string().map_err(|value| EnvError::NotUnicode {
+            key: key.to_string(),
+            value,
+        }),
+    }
+}
+
+/// Returns the value of the environment variable `key` parsed as `T`.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::env::{self, EnvError};
+///
+/// # #[derive(Debug)]
+/// struct Port;
+///
+/// # std::env::set_var("NOT_FOUND_ERROR_DOCTEST_PORT", "8080");
+/// # std::env::set_var("NOT_FOUND_ERROR_DOCTEST_BAD_PORT", "eighty");
+/// assert_eq!(env::required_parsed::<Port, u16>("NOT_FOUND_ERROR_DOCTEST_PORT").unwrap(), 8080);
+///
+/// let error = env::required_parsed::<Port, u16>("NOT_FOUND_ERROR_DOCTEST_BAD_PORT").unwrap_err();
+/// assert!(matches!(error, EnvError::Parse { .. }));
+/// ```
+pub fn required_parsed<M, T: FromStr>(key: &str) -> Result<T, EnvError<M, T::Err>> {
+    let value = required::<M>(key).map_err(|error| match error {
+        EnvError::NotFound(error) => EnvError::NotFound(error),
+        EnvError::NotUnicode {
+            key,
+            value,
+        } => EnvError::NotUnicode {
+            key,
+            value,
+        },
+    })?;
+    value.parse().map_err(|source| EnvError::Parse {
+        key: key.to_string(),
+        source,
+    })
+}
+
+/// Returns the first directory listed in the `PATH`-style variable `key` that satisfies the predicate.
+///
+/// The directories are split with [`std::env::split_paths`]. An unset variable is treated as an empty list, so the error records `key` in both cases.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use not_found_error::env;
+/// use not_found_error::NotFoundByKey;
+///
+/// #[derive(PartialEq, Debug)]
+/// struct PluginDir;
+///
+/// let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+/// # std::env::set_var("NOT_FOUND_ERROR_DOCTEST_PLUGIN_PATH", std::env::join_paths([manifest_dir.join("src"), manifest_dir.to_path_buf()]).unwrap());
+/// let dir = env::find_in_path_list::<PluginDir>("NOT_FOUND_ERROR_DOCTEST_PLUGIN_PATH", |dir| dir.join("Cargo.toml").is_file());
+/// assert_eq!(dir.unwrap(), manifest_dir);
+///
+/// let dir = env::find_in_path_list::<PluginDir>("NOT_FOUND_ERROR_DOCTEST_UNSET", |_| true);
+/// assert_eq!(dir, Err(NotFoundByKey::new("NOT_FOUND_ERROR_DOCTEST_UNSET".to_string())));
+/// ```
+pub fn find_in_path_list<M>(key: &str, mut f: impl FnMut(&Path) -> bool) -> Result<PathBuf, NotFoundByKey<M, String>> {
+    std::env::var_os(key)
+        .and_then(|value| std::env::split_paths(&value).find(|dir| f(dir)))
+        .ok_or_else(|| NotFoundByKey::new(key.to_string()))
+}
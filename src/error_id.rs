@@ -0,0 +1,182 @@
+use std::any::type_name;
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::NotFoundError;
+
+/// A stable, human-assigned identifier of a marker type (e.g. `E_USER_NOT_FOUND`).
+///
+/// Unlike [`type_name`], the identifier doesn't change when the type is renamed or moved to another module.
+///
+/// The identifier is surfaced through [`NotFoundError::error_id`], [`NotFoundError::display_with_id`], the serde impls (feature `serde`) and the miette diagnostic code (feature `miette`).
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{ErrorId, NotFoundError};
+///
+/// struct User;
+///
+/// impl ErrorId for User {
+///     const ERROR_ID: &'static str = "E_USER_NOT_FOUND";
+/// }
+///
+/// let error = NotFoundError::<User>::new();
+/// assert_eq!(error.error_id(), "E_USER_NOT_FOUND");
+/// assert!(error.display_with_id().to_string().starts_with("[E_USER_NOT_FOUND] "));
+/// ```
+pub trait ErrorId {
+    const ERROR_ID: &'static str;
+}
+
+impl<T: ErrorId + ?Sized> NotFoundError<T> {
+    /// Returns the stable identifier of this error.
+    pub fn error_id(&self) -> &'static str {
+        T::ERROR_ID
+    }
+
+    /// Returns a value that displays this error prefixed with its stable identifier.
+    pub fn display_with_id(&self) -> DisplayWithId<T> {
+        DisplayWithId(PhantomData)
+    }
+}
+
+/// Displays a [`NotFoundError`] prefixed with its stable identifier (see [`NotFoundError::display_with_id`])
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct DisplayWithId<T: ?Sized>(pub PhantomData<T>);
+
+impl<T: ErrorId + ?Sized> Display for DisplayWithId<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", T::ERROR_ID, NotFoundError::<T>::new())
+    }
+}
+
+/// A single entry of the [`ErrorIdRegistry`]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct ErrorIdEntry {
+    pub error_id: &'static str,
+    pub type_name: &'static str,
+}
+
+impl ErrorIdEntry {
+    /// Creates an entry for type `T`.
+    pub fn of<T: ErrorId>() -> Self {
+        Self {
+            error_id: T::ERROR_ID,
+            type_name: type_name::<T>(),
+        }
+    }
+}
+
+/// A list of known error identifiers (e.g. for documentation generation)
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{ErrorId, ErrorIdRegistry};
+///
+/// struct User;
+///
+/// impl ErrorId for User {
+///     const ERROR_ID: &'static str = "E_USER_NOT_FOUND";
+/// }
+///
+/// struct Order;
+///
+/// impl ErrorId for Order {
+///     const ERROR_ID: &'static str = "E_ORDER_NOT_FOUND";
+/// }
+///
+/// let mut registry = ErrorIdRegistry::new();
+/// registry.register::<User>().register::<Order>();
+///
+/// assert_eq!(registry.error_ids().collect::<Vec<_>>(), vec!["E_ORDER_NOT_FOUND", "E_USER_NOT_FOUND"]);
+/// assert!(registry.get("E_USER_NOT_FOUND").is_some());
+/// ```
+#[derive(Eq, PartialEq, Hash, Clone, Default, Debug)]
+pub struct ErrorIdRegistry {
+    /// Entries sorted by identifier
+    pub entries: Vec<ErrorIdEntry>,
+}
+
+impl ErrorIdRegistry {
+    /// Creates an empty `ErrorIdRegistry`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds type `T` to the registry.
+    ///
+    /// Registering the same identifier twice replaces the previous entry.
+    pub fn register<T: ErrorId>(&mut self) -> &mut Self {
+        let entry = ErrorIdEntry::of::<T>();
+        match self
+            .entries
+            .binary_search_by_key(&entry.error_id, |entry| entry.error_id)
+        {
+            Ok(index) => self.entries[index] = entry,
+            Err(index) => self.entries.insert(index, entry),
+        }
+        self
+    }
+
+    /// Returns the entry with the given identifier.
+    pub fn get(&self, error_id: &str) -> Option<&ErrorIdEntry> {
+        self.entries
+            .binary_search_by_key(&error_id, |entry| entry.error_id)
+            .ok()
+            .map(|index| &self.entries[index])
+    }
+
+    /// Returns an iterator over the registered identifiers (sorted).
+    pub fn error_ids(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.entries.iter().map(|entry| entry.error_id)
+    }
+}
+
+#[cfg(feature = "serde")]
+pub(crate) mod serde_impls {
+    use std::borrow::Cow;
+
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::ErrorId;
+    use crate::NotFoundError;
+
+    #[derive(Serialize, Deserialize)]
+    pub(crate) struct Repr<'a> {
+        pub(crate) error_id: Cow<'a, str>,
+        pub(crate) message: Cow<'a, str>,
+    }
+
+    /// Serializes as `{ "error_id": "E_USER_NOT_FOUND", "message": "User not found" }`
+    impl<T: ErrorId> Serialize for NotFoundError<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Repr {
+                error_id: Cow::Borrowed(T::ERROR_ID),
+                message: Cow::Owned(self.to_string()),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    /// Deserializes from the representation produced by `Serialize`, checking that the identifier matches `T::ERROR_ID`
+    impl<'de, T: ErrorId> Deserialize<'de> for NotFoundError<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let repr = Repr::deserialize(deserializer)?;
+            if repr.error_id == T::ERROR_ID {
+                Ok(NotFoundError::new())
+            } else {
+                Err(D::Error::custom(format_args!("expected error_id {:?}, found {:?}", T::ERROR_ID, repr.error_id)))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "miette")]
+impl<T: ErrorId + std::fmt::Debug> miette::Diagnostic for NotFoundError<T> {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(T::ERROR_ID))
+    }
+}
@@ -0,0 +1,34 @@
+use crate::NotEnoughItemsError;
+
+/// An extension trait for iterators with a known length
+pub trait ExactSizeIteratorExt: ExactSizeIterator {
+    /// Returns the iterator unchanged if it has at least `n` remaining elements, or an error with the expected and actual counts.
+    ///
+    /// Useful for validating batch inputs before processing them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{ExactSizeIteratorExt, NotEnoughItemsError};
+    ///
+    /// let batch = vec![1, 2, 3];
+    /// let iter = batch.iter().require_len_at_least(2).unwrap();
+    /// assert_eq!(iter.sum::<i32>(), 6);
+    ///
+    /// let error = batch.iter().require_len_at_least(5).unwrap_err();
+    /// assert_eq!(error, NotEnoughItemsError::new(5, 3));
+    /// ```
+    fn require_len_at_least(self, n: usize) -> Result<Self, NotEnoughItemsError<Self::Item>>
+    where
+        Self: Sized,
+    {
+        let len = self.len();
+        if len >= n {
+            Ok(self)
+        } else {
+            Err(NotEnoughItemsError::new(n, len))
+        }
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIteratorExt for I {}
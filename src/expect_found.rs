@@ -0,0 +1,41 @@
+use std::panic::Location;
+
+use crate::NotFoundError;
+
+/// An extension trait for `Option<T>` that panics with a consistent, greppable message if the value is absent
+///
+/// Use it when absence is a bug: the panic message has the format `{type} not found at {file}:{line}:{column}`, where the location is the caller's.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::ExpectFound;
+///
+/// assert_eq!(Some(1).expect_found(), 1);
+/// ```
+///
+/// ```should_panic
+/// use not_found_error::ExpectFound;
+///
+/// // Panics with a message like "i32 not found at src/main.rs:5:13"
+/// None::<i32>.expect_found();
+/// ```
+pub trait ExpectFound {
+    type T;
+
+    #[track_caller]
+    fn expect_found(self) -> Self::T;
+}
+
+impl<T> ExpectFound for Option<T> {
+    type T = T;
+
+    #[inline(always)]
+    #[track_caller]
+    fn expect_found(self) -> T {
+        match self {
+            Some(value) => value,
+            None => panic!("{} at {}", NotFoundError::<T>::new(), Location::caller()),
+        }
+    }
+}
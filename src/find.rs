@@ -0,0 +1,91 @@
+use std::future::Future;
+
+use crate::{FindError, RequireFlatten};
+
+/// A source of values that can be looked up by key, such as a database table or a remote API
+///
+/// Used by the cache extensions to fill the cache on a miss.
+/// Implemented for all closures of the form `Fn(&K) -> Result<Option<V>, E>`.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{Find, FindError, NotFoundError};
+///
+/// struct Users;
+///
+/// impl Find<u32> for Users {
+///     type Output = String;
+///     type Error = std::io::Error;
+///
+///     fn find(&self, id: &u32) -> Result<Option<String>, Self::Error> {
+///         Ok((*id == 1).then(|| "alice".to_string()))
+///     }
+/// }
+///
+/// assert_eq!(Users.find_required(&1).unwrap(), "alice");
+/// assert!(matches!(Users.find_required(&2), Err(FindError::NotFound(NotFoundError { .. }))));
+///
+/// let users = |id: &u32| Ok::<_, String>((*id == 1).then_some("alice"));
+/// assert_eq!(users.find_required(&1), Ok("alice"));
+/// ```
+pub trait Find<K: ?Sized> {
+    type Output;
+    type Error;
+
+    /// Looks up the value for `key`.
+    fn find(&self, key: &K) -> Result<Option<Self::Output>, Self::Error>;
+
+    /// Looks up the value for `key`, converting `None` into an error.
+    fn find_required(&self, key: &K) -> Result<Self::Output, FindError<Self::Output, Self::Error>> {
+        self.find(key).require_flatten()
+    }
+}
+
+impl<K: ?Sized, V, E, F: Fn(&K) -> Result<Option<V>, E>> Find<K> for F {
+    type Output = V;
+    type Error = E;
+
+    #[inline(always)]
+    fn find(&self, key: &K) -> Result<Option<V>, E> {
+        self(key)
+    }
+}
+
+/// An asynchronous source of values that can be looked up by key (the async counterpart of [`Find`])
+///
+/// With the `tower` feature, it can be converted into a `tower::Service` via `FindService` (and back via `ServiceFind`).
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use not_found_error::{FindAsync, FindError, NotFoundError};
+///
+/// struct Users;
+///
+/// impl FindAsync<u32> for Users {
+///     type Output = String;
+///     type Error = std::io::Error;
+///
+///     async fn find(&self, id: &u32) -> Result<Option<String>, Self::Error> {
+///         Ok((*id == 1).then(|| "alice".to_string()))
+///     }
+/// }
+///
+/// assert_eq!(block_on(Users.find_required(&1)).unwrap(), "alice");
+/// assert!(matches!(block_on(Users.find_required(&2)), Err(FindError::NotFound(NotFoundError { .. }))));
+/// ```
+pub trait FindAsync<K: ?Sized> {
+    type Output;
+    type Error;
+
+    /// Looks up the value for `key`.
+    fn find(&self, key: &K) -> impl Future<Output = Result<Option<Self::Output>, Self::Error>> + Send;
+
+    /// Looks up the value for `key`, converting `None` into an error.
+    fn find_required(&self, key: &K) -> impl Future<Output = Result<Self::Output, FindError<Self::Output, Self::Error>>> + Send {
+        let find = self.find(key);
+        async move { find.await.require_flatten() }
+    }
+}
@@ -0,0 +1,180 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::{mark_not_found, AnyNotFound, NotFoundByKey, NotFoundError, RequireOr};
+
+/// Represents an error indicating that a lookup either found nothing or failed with another error.
+///
+/// This is the error shape of most database query helpers that return `Result<Option<T>, E>`.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use not_found_error::{FindError, NotFoundError};
+///
+/// let error = FindError::<i32, io::Error>::from(NotFoundError::new());
+/// assert_eq!(error.to_string(), "i32 not found");
+///
+/// let error = FindError::<i32, io::Error>::Other(io::Error::other("connection lost"));
+/// assert_eq!(error.to_string(), "connection lost");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub enum FindError<T, E> {
+    NotFound(NotFoundError<T>),
+    Other(E),
+}
+
+/// A type alias for the error returned by [`try_locate`](crate::try_locate)
+pub type TryLocateError<T, E> = FindError<T, E>;
+
+impl<T, E: Display> Display for FindError<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FindError::NotFound(error) => Display::fmt(error, f),
+            FindError::Other(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<T: Debug, E: Error + 'static> Error for FindError<T, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FindError::NotFound(_) => mark_not_found(None),
+            FindError::Other(error) => Some(error),
+        }
+    }
+}
+
+impl<T, E> From<NotFoundError<T>> for FindError<T, E> {
+    fn from(error: NotFoundError<T>) -> Self {
+        Self::NotFound(error)
+    }
+}
+
+impl<T, E> From<RequireOr<E, T>> for FindError<T, E> {
+    fn from(error: RequireOr<E, T>) -> Self {
+        match error {
+            RequireOr::NotFound(error) => Self::NotFound(error),
+            RequireOr::Other(error) => Self::Other(error),
+        }
+    }
+}
+
+impl<T: Debug + Send + Sync + 'static> From<FindError<T, std::io::Error>> for std::io::Error {
+    fn from(error: FindError<T, std::io::Error>) -> Self {
+        match error {
+            FindError::NotFound(error) => std::io::Error::new(std::io::ErrorKind::NotFound, error),
+            FindError::Other(error) => error,
+        }
+    }
+}
+
+impl<T, E> AnyNotFound for FindError<T, E> {
+    fn is_not_found(&self) -> bool {
+        matches!(self, FindError::NotFound(_))
+    }
+}
+
+/// Represents an error indicating that a lookup by key either found nothing or failed with another error.
+///
+/// The keyed counterpart of [`FindError`]: the not-found case records the key that was looked up.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use not_found_error::{FindByKeyError, FindError, NotFoundByKey, NotFoundError};
+///
+/// let error = FindByKeyError::<i32, &str, io::Error>::from(NotFoundByKey::new("answer"));
+/// assert_eq!(error.to_string(), "i32 not found by key \"answer\"");
+/// assert!(matches!(FindError::from(error), FindError::<i32, io::Error>::NotFound(NotFoundError { .. })));
+///
+/// let error = FindByKeyError::<i32, &str, io::Error>::Other(io::Error::other("connection lost"));
+/// assert_eq!(error.to_string(), "connection lost");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub enum FindByKeyError<T, K, E> {
+    NotFound(NotFoundByKey<T, K>),
+    Other(E),
+}
+
+impl<T, K: Debug, E: Display> Display for FindByKeyError<T, K, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FindByKeyError::NotFound(error) => Display::fmt(error, f),
+            FindByKeyError::Other(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<T: Debug, K: Debug, E: Error + 'static> Error for FindByKeyError<T, K, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FindByKeyError::NotFound(_) => mark_not_found(None),
+            FindByKeyError::Other(error) => Some(error),
+        }
+    }
+}
+
+impl<T, K, E> From<NotFoundByKey<T, K>> for FindByKeyError<T, K, E> {
+    fn from(error: NotFoundByKey<T, K>) -> Self {
+        Self::NotFound(error)
+    }
+}
+
+impl<T, K, E> From<FindByKeyError<T, K, E>> for FindError<T, E> {
+    fn from(error: FindByKeyError<T, K, E>) -> Self {
+        match error {
+            FindByKeyError::NotFound(error) => Self::NotFound(error.into()),
+            FindByKeyError::Other(error) => Self::Other(error),
+        }
+    }
+}
+
+impl<T: Debug + Send + Sync + 'static, K: Debug + Send + Sync + 'static> From<FindByKeyError<T, K, std::io::Error>> for std::io::Error {
+    fn from(error: FindByKeyError<T, K, std::io::Error>) -> Self {
+        match error {
+            FindByKeyError::NotFound(error) => std::io::Error::new(std::io::ErrorKind::NotFound, error),
+            FindByKeyError::Other(error) => error,
+        }
+    }
+}
+
+impl<T, K, E> AnyNotFound for FindByKeyError<T, K, E> {
+    fn is_not_found(&self) -> bool {
+        matches!(self, FindByKeyError::NotFound(_))
+    }
+}
+
+/// An extension trait for `Result<Option<T>, E>` to convert it to `Result<T, FindError<T, E>>`
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{FindError, NotFoundError, RequireFlatten};
+///
+/// # fn find_user(id: u32) -> Result<Option<String>, String> { Ok((id == 1).then(|| "alice".into())) }
+/// assert_eq!(find_user(1).require_flatten(), Ok("alice".to_string()));
+/// assert_eq!(find_user(2).require_flatten(), Err(FindError::NotFound(NotFoundError::new())));
+/// ```
+pub trait RequireFlatten {
+    type T;
+    type E;
+
+    fn require_flatten(self) -> Result<Self::T, FindError<Self::T, Self::E>>;
+}
+
+impl<T, E> RequireFlatten for Result<Option<T>, E> {
+    type T = T;
+    type E = E;
+
+    #[inline(always)]
+    fn require_flatten(self) -> Result<T, FindError<T, E>> {
+        match self {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => Err(FindError::NotFound(NotFoundError::new())),
+            Err(error) => Err(FindError::Other(error)),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::{mark_not_found, AnyNotFound, NotFoundError};
+
+/// Represents an error indicating that a value was not found in any of the alternatives.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::FirstFoundError;
+///
+/// let error = FirstFoundError::<i32>::new(3);
+/// assert_eq!(error.to_string(), "i32 not found in 3 alternatives");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct FirstFoundError<T> {
+    /// The number of alternatives that were evaluated
+    pub tried: usize,
+    pub phantom: PhantomData<T>,
+}
+
+impl<T> FirstFoundError<T> {
+    /// Creates a new `FirstFoundError`.
+    pub fn new(tried: usize) -> Self {
+        Self {
+            tried,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Display for FirstFoundError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let noun = if self.tried == 1 { "alternative" } else { "alternatives" };
+        write!(f, "{} not found in {} {}", type_name::<T>(), self.tried, noun)
+    }
+}
+
+impl<T: Debug> Error for FirstFoundError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        mark_not_found(None)
+    }
+}
+
+impl<T> AnyNotFound for FirstFoundError<T> {
+    fn is_not_found(&self) -> bool {
+        true
+    }
+}
+
+impl<T> From<FirstFoundError<T>> for NotFoundError<T> {
+    fn from(_error: FirstFoundError<T>) -> Self {
+        NotFoundError::new()
+    }
+}
+
+/// Evaluates the alternatives in order and returns the first found value.
+///
+/// The alternatives are evaluated lazily: the remaining ones are not called after a value is found.
+///
+/// # Examples
+///
+/// ```
+/// # use not_found_error::{first_found, FirstFoundError};
+/// # fn from_flag() -> Option<u16> { None }
+/// # fn from_env() -> Option<u16> { Some(8080) }
+/// # fn from_file() -> Option<u16> { unreachable!() }
+/// let port = first_found([from_flag, from_env, from_file]);
+/// assert_eq!(port, Ok(8080));
+///
+/// let port = first_found([from_flag, from_flag]);
+/// assert_eq!(port, Err(FirstFoundError::new(2)));
+/// ```
+pub fn first_found<T, F: FnOnce() -> Option<T>>(alternatives: impl IntoIterator<Item = F>) -> Result<T, FirstFoundError<T>> {
+    let mut tried = 0;
+    for alternative in alternatives {
+        tried += 1;
+        if let Some(value) = alternative() {
+            return Ok(value);
+        }
+    }
+    Err(FirstFoundError::new(tried))
+}
+
+/// Polls the alternatives concurrently and returns the first found value (feature `futures`).
+///
+/// Resolves as soon as any of the futures resolves to `Some` (the remaining ones are dropped), or with an error after all of them resolve to `None`.
+/// Useful for querying replicas or mirrors.
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::future::{self, BoxFuture, FutureExt};
+/// use not_found_error::{first_found_async, FirstFoundError};
+///
+/// let mirrors: Vec<BoxFuture<Option<&str>>> = vec![future::ready(None).boxed(), future::pending().boxed(), future::ready(Some("eu-1")).boxed()];
+/// assert_eq!(block_on(first_found_async(mirrors)), Ok("eu-1"));
+///
+/// let replicas = [future::ready(None::<u32>), future::ready(None)];
+/// assert_eq!(block_on(first_found_async(replicas)), Err(FirstFoundError::new(2)));
+/// ```
+#[cfg(feature = "futures")]
+pub async fn first_found_async<T, F: std::future::Future<Output = Option<T>>>(alternatives: impl IntoIterator<Item = F>) -> Result<T, FirstFoundError<T>> {
+    use futures::stream::{FuturesUnordered, StreamExt};
+
+    let mut pending = alternatives.into_iter().collect::<FuturesUnordered<F>>();
+    let tried = pending.len();
+    while let Some(option) = pending.next().await {
+        if let Some(value) = option {
+            return Ok(value);
+        }
+    }
+    Err(FirstFoundError::new(tried))
+}
+
+/// A chain of lazily evaluated alternatives (see [`OrFind`])
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct FindChain<T> {
+    pub value: Option<T>,
+    /// The number of alternatives that were evaluated
+    pub tried: usize,
+}
+
+impl<T> FindChain<T> {
+    /// Evaluates `f` if the value hasn't been found yet.
+    pub fn or_find(self, f: impl FnOnce() -> Option<T>) -> Self {
+        match self.value {
+            Some(_) => self,
+            None => Self {
+                value: f(),
+                tried: self.tried + 1,
+            },
+        }
+    }
+
+    /// Converts the chain to `Result<T, FirstFoundError<T>>`.
+    pub fn require(self) -> Result<T, FirstFoundError<T>> {
+        self.value.ok_or(FirstFoundError::new(self.tried))
+    }
+}
+
+/// An extension trait for `Option<T>` to chain lazily evaluated alternatives
+///
+/// # Examples
+///
+/// ```
+/// # use not_found_error::OrFind;
+/// # let flag: Option<u16> = None;
+/// # fn from_env() -> Option<u16> { None }
+/// # fn from_file() -> Option<u16> { Some(8080) }
+/// let port = flag
+///     .or_find(from_env)
+///     .or_find(from_file)
+///     .or_find(|| unreachable!())
+///     .require();
+/// assert_eq!(port, Ok(8080));
+/// ```
+pub trait OrFind {
+    type T;
+
+    fn or_find(self, f: impl FnOnce() -> Option<Self::T>) -> FindChain<Self::T>;
+}
+
+impl<T> OrFind for Option<T> {
+    type T = T;
+
+    fn or_find(self, f: impl FnOnce() -> Option<Self::T>) -> FindChain<Self::T> {
+        FindChain {
+            value: self,
+            tried: 1,
+        }
+        .or_find(f)
+    }
+}
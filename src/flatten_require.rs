@@ -0,0 +1,32 @@
+use crate::NotFoundError;
+
+/// An extension trait for `Option<Option<T>>` to convert it to `Result<T, NotFoundError<T>>`
+///
+/// Nested options often appear in chains like `map.get(k).map(|v| v.field)`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use not_found_error::{FlattenRequire, NotFoundError};
+///
+/// let emails = HashMap::from([("alice", Some("alice@example.com")), ("bob", None)]);
+///
+/// assert_eq!(emails.get("alice").copied().flatten_require(), Ok("alice@example.com"));
+/// assert_eq!(emails.get("bob").copied().flatten_require(), Err(NotFoundError::new()));
+/// assert_eq!(emails.get("carol").copied().flatten_require(), Err(NotFoundError::new()));
+/// ```
+pub trait FlattenRequire {
+    type T;
+
+    fn flatten_require(self) -> Result<Self::T, NotFoundError<Self::T>>;
+}
+
+impl<T> FlattenRequire for Option<Option<T>> {
+    type T = T;
+
+    #[inline(always)]
+    fn flatten_require(self) -> Result<T, NotFoundError<T>> {
+        self.flatten().ok_or(NotFoundError::new())
+    }
+}
@@ -0,0 +1,493 @@
+//! Helpers for locating files in the ancestors of a directory and executables in `PATH`.
+//!
+//! The `_async` variants use `tokio::fs` (feature `tokio`) and the `_async_std` variants use `async_std::fs` (feature `async-std`), so async services don't block the runtime.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::path::Path;
+//! use not_found_error::fs;
+//!
+//! let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+//! let root = fs::find_in_ancestors(&src, "Cargo.toml").unwrap();
+//! assert_eq!(root, Path::new(env!("CARGO_MANIFEST_DIR")));
+//! ```
+
+use std::ffi::{OsStr, OsString};
+use std::fs::DirEntry;
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+use std::future::Future;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{try_locate, FindError, NotFoundByKey, NotFoundError};
+
+/// A type alias for the error returned by [`find_entry`]: either an IO error or `NotFoundError<DirEntry>`
+pub type FindEntryError = FindError<DirEntry, io::Error>;
+
+/// A marker type for the root directory of a workspace (e.g. a directory that contains `Cargo.toml` or `.git`)
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default, Debug)]
+pub struct WorkspaceRoot;
+
+/// A marker type for an executable looked up in `PATH`
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default, Debug)]
+pub struct Executable;
+
+/// Returns the closest ancestor of `start` (including `start` itself) that contains `file_name`.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use not_found_error::fs::{self, WorkspaceRoot};
+/// use not_found_error::NotFoundError;
+///
+/// let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+/// assert_eq!(fs::find_in_ancestors(manifest_dir.join("src"), "Cargo.toml").unwrap(), manifest_dir);
+/// assert_eq!(fs::find_in_ancestors(manifest_dir, "no-such-file.toml"), Err(NotFoundError::<WorkspaceRoot>::new()));
+/// ```
+pub fn find_in_ancestors(start: impl AsRef<Path>, file_name: impl AsRef<Path>) -> Result<PathBuf, NotFoundError<WorkspaceRoot>> {
+    find_ancestor_with(start.as_ref(), file_name.as_ref())
+        .map(Path::to_path_buf)
+        .ok_or(NotFoundError::new())
+}
+
+/// Returns the path to `file_name` in the closest ancestor of `start` (including `start` itself) that contains it.
+///
+/// The marker type `M` labels the error.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use not_found_error::fs;
+/// use not_found_error::NotFoundError;
+///
+/// #[derive(PartialEq, Debug)]
+/// struct Manifest;
+///
+/// let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+/// assert_eq!(fs::find_file_upwards::<Manifest>(manifest_dir.join("src"), "Cargo.toml").unwrap(), manifest_dir.join("Cargo.toml"));
+/// assert_eq!(fs::find_file_upwards::<Manifest>(manifest_dir, "no-such-file.toml"), Err(NotFoundError::new()));
+/// ```
+pub fn find_file_upwards<M>(start: impl AsRef<Path>, file_name: impl AsRef<Path>) -> Result<PathBuf, NotFoundError<M>> {
+    let file_name = file_name.as_ref();
+    find_ancestor_with(start.as_ref(), file_name)
+        .map(|dir| dir.join(file_name))
+        .ok_or(NotFoundError::new())
+}
+
+fn find_ancestor_with<'a>(start: &'a Path, file_name: &Path) -> Option<&'a Path> {
+    start.ancestors().find(|dir| dir.join(file_name).exists())
+}
+
+/// Returns the first entry of the directory that satisfies the predicate.
+///
+/// Unlike a plain [`std::fs::read_dir`] loop, the error distinguishes IO errors from the case when no entry matches.
+/// The search is not recursive (see `find_entry_recursive` behind the `walkdir` feature).
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::fs;
+/// use not_found_error::FindError;
+///
+/// let manifest_dir = env!("CARGO_MANIFEST_DIR");
+///
+/// let entry = fs::find_entry(manifest_dir, |entry| entry.file_name() == "Cargo.toml").unwrap();
+/// assert_eq!(entry.file_name(), "Cargo.toml");
+///
+/// let error = fs::find_entry(manifest_dir, |entry| entry.file_name() == "missing.toml").unwrap_err();
+/// assert!(matches!(error, FindError::NotFound(_)));
+///
+/// let error = fs::find_entry("/no/such/dir", |_| true).unwrap_err();
+/// assert!(matches!(error, FindError::Other(_)));
+/// ```
+pub fn find_entry(dir: impl AsRef<Path>, f: impl FnMut(&DirEntry) -> bool) -> Result<DirEntry, FindEntryError> {
+    let entries = std::fs::read_dir(dir).map_err(FindError::Other)?;
+    try_locate(entries, f)
+}
+
+/// Returns the first entry of the directory tree (walked recursively, including `dir` itself) that satisfies the predicate (feature `walkdir`).
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::fs;
+/// use not_found_error::FindError;
+///
+/// let manifest_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+///
+/// let entry = fs::find_entry_recursive(manifest_dir.join("src"), |entry| entry.file_name() == "lib.rs").unwrap();
+/// assert_eq!(entry.path(), manifest_dir.join("src").join("lib.rs"));
+///
+/// let error = fs::find_entry_recursive("/no/such/dir", |_| false).unwrap_err();
+/// assert!(matches!(error, FindError::Other(_)));
+/// ```
+#[cfg(feature = "walkdir")]
+pub fn find_entry_recursive(dir: impl AsRef<Path>, f: impl FnMut(&walkdir::DirEntry) -> bool) -> Result<walkdir::DirEntry, FindError<walkdir::DirEntry, walkdir::Error>> {
+    try_locate(walkdir::WalkDir::new(dir), f)
+}
+
+/// Returns the path to the executable `name` in the directories of the `PATH` environment variable (like `which`).
+///
+/// On Windows, the extensions from the `PATHEXT` environment variable are tried if `name` has no extension.
+/// If `PATH` is unset, no directories are searched.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::fs;
+///
+/// let error = fs::find_executable("not-found-error-no-such-binary").unwrap_err();
+/// assert_eq!(error.to_string(), "not_found_error::fs::Executable not found by key \"not-found-error-no-such-binary\"");
+/// ```
+pub fn find_executable(name: &str) -> Result<PathBuf, NotFoundByKey<Executable, String>> {
+    find_executable_in(name, std::env::var_os("PATH").unwrap_or_default())
+}
+
+/// Returns the path to the executable `name` in the directories of `paths` (formatted like the `PATH` environment variable).
+///
+/// Empty entries are skipped. Some shells treat them as the current directory, which would let a file in the current directory shadow an actual executable.
+///
+/// # Examples
+///
+/// ```
+/// use std::env;
+/// use std::path::PathBuf;
+/// use not_found_error::fs;
+///
+/// let cargo = PathBuf::from(env::var_os("CARGO").unwrap());
+/// let paths = env::join_paths([cargo.parent().unwrap()]).unwrap();
+/// assert_eq!(fs::find_executable_in(cargo.file_stem().unwrap().to_str().unwrap(), &paths).unwrap(), cargo);
+///
+/// // The empty entry doesn't stand for the current directory
+/// env::set_current_dir(cargo.parent().unwrap()).unwrap();
+/// assert!(fs::find_executable_in(cargo.file_stem().unwrap().to_str().unwrap(), "").is_err());
+/// ```
+pub fn find_executable_in(name: &str, paths: impl AsRef<OsStr>) -> Result<PathBuf, NotFoundByKey<Executable, String>> {
+    let candidates = executable_candidates(name);
+    search_dirs(paths.as_ref())
+        .flat_map(|dir| candidates.iter().map(move |candidate| dir.join(candidate)))
+        .find(|path| is_executable(path))
+        .ok_or_else(|| NotFoundByKey::new(name.to_string()))
+}
+
+/// The async counterpart of [`find_in_ancestors`] that uses `tokio::fs` (feature `tokio`).
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use not_found_error::fs;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+/// assert_eq!(fs::find_in_ancestors_async(manifest_dir.join("src"), "Cargo.toml").await.unwrap(), manifest_dir);
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn find_in_ancestors_async(start: impl AsRef<Path>, file_name: impl AsRef<Path>) -> Result<PathBuf, NotFoundError<WorkspaceRoot>> {
+    find_ancestor_with_async(start.as_ref(), file_name.as_ref(), tokio::fs::metadata)
+        .await
+        .map(Path::to_path_buf)
+        .ok_or(NotFoundError::new())
+}
+
+/// The async counterpart of [`find_file_upwards`] that uses `tokio::fs` (feature `tokio`).
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use not_found_error::fs;
+/// use not_found_error::NotFoundError;
+///
+/// #[derive(PartialEq, Debug)]
+/// struct Manifest;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+/// assert_eq!(fs::find_file_upwards_async::<Manifest>(manifest_dir, "no-such-file.toml").await, Err(NotFoundError::new()));
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn find_file_upwards_async<M>(start: impl AsRef<Path>, file_name: impl AsRef<Path>) -> Result<PathBuf, NotFoundError<M>> {
+    let file_name = file_name.as_ref();
+    find_ancestor_with_async(start.as_ref(), file_name, tokio::fs::metadata)
+        .await
+        .map(|dir| dir.join(file_name))
+        .ok_or(NotFoundError::new())
+}
+
+/// The async counterpart of [`find_executable`] that uses `tokio::fs` (feature `tokio`).
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::fs;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// assert!(fs::find_executable_async("not-found-error-no-such-binary").await.is_err());
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn find_executable_async(name: &str) -> Result<PathBuf, NotFoundByKey<Executable, String>> {
+    find_executable_in_async(name, std::env::var_os("PATH").unwrap_or_default()).await
+}
+
+/// The async counterpart of [`find_executable_in`] that uses `tokio::fs` (feature `tokio`).
+///
+/// # Examples
+///
+/// ```
+/// use std::env;
+/// use std::path::PathBuf;
+/// use not_found_error::fs;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let cargo = PathBuf::from(env::var_os("CARGO").unwrap());
+/// let paths = env::join_paths([cargo.parent().unwrap()]).unwrap();
+/// assert_eq!(fs::find_executable_in_async(cargo.file_stem().unwrap().to_str().unwrap(), &paths).await.unwrap(), cargo);
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn find_executable_in_async(name: &str, paths: impl AsRef<OsStr>) -> Result<PathBuf, NotFoundByKey<Executable, String>> {
+    find_executable_in_with_async(name, paths.as_ref(), tokio::fs::metadata).await
+}
+
+/// The async counterpart of [`PathExt::require_exists`](crate::PathExt::require_exists) that uses `tokio::fs` (feature `tokio`).
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use not_found_error::fs;
+/// use not_found_error::NotFoundByKey;
+///
+/// #[derive(PartialEq, Debug)]
+/// struct Config;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+/// let manifest = manifest_dir.join("Cargo.toml");
+/// assert_eq!(fs::require_exists_async::<Config>(&manifest).await, Ok(manifest.clone()));
+/// assert_eq!(fs::require_file_async::<Config>(&manifest).await, Ok(manifest.clone()));
+/// assert_eq!(fs::require_dir_async::<Config>(&manifest).await, Err(NotFoundByKey::new(manifest)));
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn require_exists_async<M>(path: impl AsRef<Path>) -> Result<PathBuf, NotFoundByKey<M, PathBuf>> {
+    require_metadata_with_async(path.as_ref(), |_| true, tokio::fs::metadata).await
+}
+
+/// The async counterpart of [`PathExt::require_file`](crate::PathExt::require_file) that uses `tokio::fs` (feature `tokio`).
+#[cfg(feature = "tokio")]
+pub async fn require_file_async<M>(path: impl AsRef<Path>) -> Result<PathBuf, NotFoundByKey<M, PathBuf>> {
+    require_metadata_with_async(path.as_ref(), std::fs::Metadata::is_file, tokio::fs::metadata).await
+}
+
+/// The async counterpart of [`PathExt::require_dir`](crate::PathExt::require_dir) that uses `tokio::fs` (feature `tokio`).
+#[cfg(feature = "tokio")]
+pub async fn require_dir_async<M>(path: impl AsRef<Path>) -> Result<PathBuf, NotFoundByKey<M, PathBuf>> {
+    require_metadata_with_async(path.as_ref(), std::fs::Metadata::is_dir, tokio::fs::metadata).await
+}
+
+/// The async counterpart of [`find_in_ancestors`] that uses `async_std::fs` (feature `async-std`).
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use not_found_error::fs;
+///
+/// async_std::task::block_on(async {
+///     let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+///     assert_eq!(fs::find_in_ancestors_async_std(manifest_dir.join("src"), "Cargo.toml").await.unwrap(), manifest_dir);
+/// });
+/// ```
+#[cfg(feature = "async-std")]
+pub async fn find_in_ancestors_async_std(start: impl AsRef<Path>, file_name: impl AsRef<Path>) -> Result<PathBuf, NotFoundError<WorkspaceRoot>> {
+    find_ancestor_with_async(start.as_ref(), file_name.as_ref(), async_std::fs::metadata)
+        .await
+        .map(Path::to_path_buf)
+        .ok_or(NotFoundError::new())
+}
+
+/// The async counterpart of [`find_file_upwards`] that uses `async_std::fs` (feature `async-std`).
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use not_found_error::fs;
+/// use not_found_error::NotFoundError;
+///
+/// #[derive(PartialEq, Debug)]
+/// struct Manifest;
+///
+/// async_std::task::block_on(async {
+///     let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+///     assert_eq!(fs::find_file_upwards_async_std::<Manifest>(manifest_dir, "no-such-file.toml").await, Err(NotFoundError::new()));
+/// });
+/// ```
+#[cfg(feature = "async-std")]
+pub async fn find_file_upwards_async_std<M>(start: impl AsRef<Path>, file_name: impl AsRef<Path>) -> Result<PathBuf, NotFoundError<M>> {
+    let file_name = file_name.as_ref();
+    find_ancestor_with_async(start.as_ref(), file_name, async_std::fs::metadata)
+        .await
+        .map(|dir| dir.join(file_name))
+        .ok_or(NotFoundError::new())
+}
+
+/// The async counterpart of [`find_executable`] that uses `async_std::fs` (feature `async-std`).
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::fs;
+///
+/// async_std::task::block_on(async {
+///     assert!(fs::find_executable_async_std("not-found-error-no-such-binary").await.is_err());
+/// });
+/// ```
+#[cfg(feature = "async-std")]
+pub async fn find_executable_async_std(name: &str) -> Result<PathBuf, NotFoundByKey<Executable, String>> {
+    find_executable_in_async_std(name, std::env::var_os("PATH").unwrap_or_default()).await
+}
+
+/// The async counterpart of [`find_executable_in`] that uses `async_std::fs` (feature `async-std`).
+///
+/// # Examples
+///
+/// ```
+/// use std::env;
+/// use std::path::PathBuf;
+/// use not_found_error::fs;
+///
+/// async_std::task::block_on(async {
+///     let cargo = PathBuf::from(env::var_os("CARGO").unwrap());
+///     let paths = env::join_paths([cargo.parent().unwrap()]).unwrap();
+///     assert_eq!(fs::find_executable_in_async_std(cargo.file_stem().unwrap().to_str().unwrap(), &paths).await.unwrap(), cargo);
+/// });
+/// ```
+#[cfg(feature = "async-std")]
+pub async fn find_executable_in_async_std(name: &str, paths: impl AsRef<OsStr>) -> Result<PathBuf, NotFoundByKey<Executable, String>> {
+    find_executable_in_with_async(name, paths.as_ref(), async_std::fs::metadata).await
+}
+
+/// The async counterpart of [`PathExt::require_exists`](crate::PathExt::require_exists) that uses `async_std::fs` (feature `async-std`).
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use not_found_error::fs;
+/// use not_found_error::NotFoundByKey;
+///
+/// #[derive(PartialEq, Debug)]
+/// struct Config;
+///
+/// async_std::task::block_on(async {
+///     let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+///     let manifest = manifest_dir.join("Cargo.toml");
+///     assert_eq!(fs::require_exists_async_std::<Config>(&manifest).await, Ok(manifest.clone()));
+///     assert_eq!(fs::require_file_async_std::<Config>(&manifest).await, Ok(manifest.clone()));
+///     assert_eq!(fs::require_dir_async_std::<Config>(&manifest).await, Err(NotFoundByKey::new(manifest)));
+/// });
+/// ```
+#[cfg(feature = "async-std")]
+pub async fn require_exists_async_std<M>(path: impl AsRef<Path>) -> Result<PathBuf, NotFoundByKey<M, PathBuf>> {
+    require_metadata_with_async(path.as_ref(), |_| true, async_std::fs::metadata).await
+}
+
+/// The async counterpart of [`PathExt::require_file`](crate::PathExt::require_file) that uses `async_std::fs` (feature `async-std`).
+#[cfg(feature = "async-std")]
+pub async fn require_file_async_std<M>(path: impl AsRef<Path>) -> Result<PathBuf, NotFoundByKey<M, PathBuf>> {
+    require_metadata_with_async(path.as_ref(), std::fs::Metadata::is_file, async_std::fs::metadata).await
+}
+
+/// The async counterpart of [`PathExt::require_dir`](crate::PathExt::require_dir) that uses `async_std::fs` (feature `async-std`).
+#[cfg(feature = "async-std")]
+pub async fn require_dir_async_std<M>(path: impl AsRef<Path>) -> Result<PathBuf, NotFoundByKey<M, PathBuf>> {
+    require_metadata_with_async(path.as_ref(), std::fs::Metadata::is_dir, async_std::fs::metadata).await
+}
+
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+async fn find_ancestor_with_async<'a, Fut: Future<Output = io::Result<std::fs::Metadata>>>(start: &'a Path, file_name: &Path, metadata: impl Fn(PathBuf) -> Fut) -> Option<&'a Path> {
+    for dir in start.ancestors() {
+        if metadata(dir.join(file_name)).await.is_ok() {
+            return Some(dir);
+        }
+    }
+    None
+}
+
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+async fn find_executable_in_with_async<Fut: Future<Output = io::Result<std::fs::Metadata>>>(name: &str, paths: &OsStr, metadata: impl Fn(PathBuf) -> Fut) -> Result<PathBuf, NotFoundByKey<Executable, String>> {
+    let candidates = executable_candidates(name);
+    for dir in search_dirs(paths) {
+        for candidate in &candidates {
+            let path = dir.join(candidate);
+            if metadata(path.clone())
+                .await
+                .is_ok_and(|metadata| is_executable_metadata(&metadata))
+            {
+                return Ok(path);
+            }
+        }
+    }
+    Err(NotFoundByKey::new(name.to_string()))
+}
+
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+async fn require_metadata_with_async<M, Fut: Future<Output = io::Result<std::fs::Metadata>>>(path: &Path, check: impl FnOnce(&std::fs::Metadata) -> bool, metadata: impl FnOnce(PathBuf) -> Fut) -> Result<PathBuf, NotFoundByKey<M, PathBuf>> {
+    match metadata(path.to_path_buf()).await {
+        Ok(metadata) if check(&metadata) => Ok(path.to_path_buf()),
+        _ => Err(NotFoundByKey::new(path.to_path_buf())),
+    }
+}
+
+/// Returns the non-empty entries of `paths` (see [`find_executable_in`]).
+fn search_dirs(paths: &OsStr) -> impl Iterator<Item = PathBuf> + '_ {
+    std::env::split_paths(paths).filter(|dir| !dir.as_os_str().is_empty())
+}
+
+#[cfg(windows)]
+fn executable_candidates(name: &str) -> Vec<OsString> {
+    if Path::new(name).extension().is_some() {
+        return vec![name.into()];
+    }
+    let extensions = std::env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string());
+    extensions
+        .split(';')
+        .filter(|extension| !extension.is_empty())
+        .map(|extension| format!("{name}{extension}").into())
+        .collect()
+}
+
+#[cfg(not(windows))]
+fn executable_candidates(name: &str) -> Vec<OsString> {
+    vec![name.into()]
+}
+
+fn is_executable(path: &Path) -> bool {
+    path.metadata()
+        .is_ok_and(|metadata| is_executable_metadata(&metadata))
+}
+
+#[cfg(unix)]
+fn is_executable_metadata(metadata: &std::fs::Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.is_file() && metadata.permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+fn is_executable_metadata(metadata: &std::fs::Metadata) -> bool {
+    metadata.is_file()
+}
@@ -0,0 +1,51 @@
+use std::future::Future;
+
+use futures::future::Map;
+use futures::FutureExt;
+
+use crate::NotFoundError;
+
+/// The future returned by [`FutureRequireExt::require`] and [`FutureRequireExt::ok_or_not_found`]
+pub type RequireFuture<F, T, B = T> = Map<F, fn(Option<T>) -> Result<T, NotFoundError<B>>>;
+
+/// An extension trait for futures that resolve to `Option<T>` (feature `futures`)
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use not_found_error::{FutureRequireExt, NotFoundError};
+///
+/// async fn find_user(id: u32) -> Option<String> {
+///     (id == 1).then(|| "alice".to_string())
+/// }
+///
+/// assert_eq!(block_on(find_user(1).require()), Ok("alice".to_string()));
+/// assert_eq!(block_on(find_user(2).require()), Err(NotFoundError::new()));
+/// ```
+pub trait FutureRequireExt<T>: Future<Output = Option<T>> + Sized {
+    /// Resolves to `Ok(value)`, or to `NotFoundError<T>` if the future resolves to `None`.
+    fn require(self) -> RequireFuture<Self, T> {
+        self.map(|option| option.ok_or(NotFoundError::new()))
+    }
+
+    /// Resolves to `Ok(value)`, or to `NotFoundError<B>` if the future resolves to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use not_found_error::{FutureRequireExt, NotFoundError};
+    ///
+    /// # #[derive(PartialEq, Debug)]
+    /// struct User;
+    ///
+    /// let name = async { None::<String> };
+    /// assert_eq!(block_on(name.ok_or_not_found::<User>()), Err(NotFoundError::<User>::new()));
+    /// ```
+    fn ok_or_not_found<B>(self) -> RequireFuture<Self, T, B> {
+        self.map(|option| option.ok_or(NotFoundError::new()))
+    }
+}
+
+impl<T, F: Future<Output = Option<T>>> FutureRequireExt<T> for F {}
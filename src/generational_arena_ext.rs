@@ -0,0 +1,42 @@
+use generational_arena::{Arena, Index};
+
+use crate::NotFoundByKey;
+
+/// An extension trait for [`Arena`] that returns keyed errors instead of `None` (feature `generational-arena`)
+///
+/// Useful for surfacing stale-handle bugs as descriptive errors.
+///
+/// # Examples
+///
+/// ```
+/// use generational_arena::Arena;
+/// use not_found_error::{ArenaExt, NotFoundByKey};
+///
+/// let mut entities = Arena::new();
+/// let index = entities.insert("player");
+///
+/// assert_eq!(entities.get_required(index), Ok(&"player"));
+/// *entities.get_mut_required(index).unwrap() = "enemy";
+///
+/// entities.remove(index);
+/// assert_eq!(entities.get_required(index), Err(NotFoundByKey::new(index)));
+/// ```
+pub trait ArenaExt<T> {
+    /// Returns the element at `index`, or an error that contains the index.
+    fn get_required(&self, index: Index) -> Result<&T, NotFoundByKey<T, Index>>;
+
+    /// Returns a mutable reference to the element at `index`, or an error that contains the index.
+    fn get_mut_required(&mut self, index: Index) -> Result<&mut T, NotFoundByKey<T, Index>>;
+}
+
+impl<T> ArenaExt<T> for Arena<T> {
+    #[inline(always)]
+    fn get_required(&self, index: Index) -> Result<&T, NotFoundByKey<T, Index>> {
+        self.get(index).ok_or(NotFoundByKey::new(index))
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self, index: Index) -> Result<&mut T, NotFoundByKey<T, Index>> {
+        self.get_mut(index).ok_or(NotFoundByKey::new(index))
+    }
+}
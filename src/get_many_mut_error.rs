@@ -0,0 +1,56 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::{mark_not_found, AnyNotFound, MissingKeysError};
+
+/// Represents an error returned by [`HashMapExt::get_many_mut_required`](crate::HashMapExt::get_many_mut_required): either some keys are missing, or a key is requested more than once.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{GetManyMutError, MissingKeysError};
+///
+/// let error = GetManyMutError::<i32, &str>::NotFound(MissingKeysError::new(vec!["carol"]));
+/// assert_eq!(error.to_string(), "i32 not found by keys [\"carol\"]");
+///
+/// let error = GetManyMutError::<i32, &str>::Overlapping { key: "alice" };
+/// assert_eq!(error.to_string(), "i32 is requested more than once by key \"alice\"");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Debug)]
+pub enum GetManyMutError<V, K> {
+    NotFound(MissingKeysError<V, K>),
+    Overlapping { key: K },
+}
+
+impl<V, K: Debug> Display for GetManyMutError<V, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GetManyMutError::NotFound(error) => Display::fmt(error, f),
+            GetManyMutError::Overlapping {
+                key,
+            } => write!(f, "{} is requested more than once by key {:?}", type_name::<V>(), key),
+        }
+    }
+}
+
+impl<V: Debug, K: Debug> Error for GetManyMutError<V, K> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GetManyMutError::NotFound(_) => mark_not_found(None),
+            _ => None,
+        }
+    }
+}
+
+impl<V, K> AnyNotFound for GetManyMutError<V, K> {
+    fn is_not_found(&self) -> bool {
+        matches!(self, GetManyMutError::NotFound(_))
+    }
+}
+
+impl<V, K> From<MissingKeysError<V, K>> for GetManyMutError<V, K> {
+    fn from(error: MissingKeysError<V, K>) -> Self {
+        Self::NotFound(error)
+    }
+}
@@ -0,0 +1,48 @@
+use hecs::{ComponentRef, Entity, Query, World};
+
+use crate::NotFoundByKey;
+
+/// An extension trait for the hecs [`World`] that returns keyed errors instead of `ComponentError` and `QueryOneError` (feature `hecs`)
+///
+/// Both a despawned entity and a missing component are reported as "not found" with the entity as the key.
+///
+/// # Examples
+///
+/// ```
+/// use hecs::World;
+/// use not_found_error::{HecsWorldExt, NotFoundByKey};
+///
+/// let mut world = World::new();
+/// let player = world.spawn((100u32, "alice"));
+///
+/// assert_eq!(*world.get_required::<&u32>(player).unwrap(), 100);
+/// assert_eq!(world.get_required::<&f32>(player).unwrap_err(), NotFoundByKey::new(player));
+///
+/// let (health, name) = world.query_one_required::<(&mut u32, &&str)>(player).unwrap();
+/// *health -= 10;
+/// assert_eq!((*health, *name), (90, "alice"));
+///
+/// world.despawn(player).unwrap();
+/// assert!(world.query_one_required::<&u32>(player).is_err());
+/// ```
+pub trait HecsWorldExt {
+    /// Returns a reference to the component of `entity` (e.g. `get_required::<&C>` or `get_required::<&mut C>`), or an error that records the entity.
+    fn get_required<'a, T: ComponentRef<'a>>(&'a self, entity: Entity) -> Result<T::Ref, NotFoundByKey<T::Component, Entity>>;
+
+    /// Returns the query item for `entity`, or an error that records the entity if it is despawned or doesn't satisfy the query.
+    fn query_one_required<Q: Query>(&mut self, entity: Entity) -> Result<Q::Item<'_>, NotFoundByKey<Q, Entity>>;
+}
+
+impl HecsWorldExt for World {
+    #[inline(always)]
+    fn get_required<'a, T: ComponentRef<'a>>(&'a self, entity: Entity) -> Result<T::Ref, NotFoundByKey<T::Component, Entity>> {
+        self.get::<T>(entity)
+            .map_err(|_error| NotFoundByKey::new(entity))
+    }
+
+    #[inline(always)]
+    fn query_one_required<Q: Query>(&mut self, entity: Entity) -> Result<Q::Item<'_>, NotFoundByKey<Q, Entity>> {
+        self.query_one_mut::<Q>(entity)
+            .map_err(|_error| NotFoundByKey::new(entity))
+    }
+}
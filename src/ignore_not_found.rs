@@ -0,0 +1,31 @@
+use crate::AnyNotFound;
+
+/// An iterator adapter that skips "not found" errors, created by [`IteratorExt::ignore_not_found`](crate::IteratorExt::ignore_not_found)
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IgnoreNotFound<I> {
+    iter: I,
+}
+
+impl<I> IgnoreNotFound<I> {
+    /// Creates a new `IgnoreNotFound` adapter.
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter,
+        }
+    }
+}
+
+impl<T, E: AnyNotFound, I: Iterator<Item = Result<T, E>>> Iterator for IgnoreNotFound<I> {
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .by_ref()
+            .find(|item| !matches!(item, Err(error) if error.is_not_found()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
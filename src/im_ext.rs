@@ -0,0 +1,90 @@
+use std::borrow::Borrow;
+use std::hash::{BuildHasher, Hash};
+
+use crate::{NotFoundByKey, NotFoundError, RequireGet};
+
+impl<K: Borrow<Q> + Hash + Eq + Clone, V: Clone, S: BuildHasher, Q: ?Sized + Hash + Eq> RequireGet<Q> for im::HashMap<K, V, S> {
+    type V = V;
+
+    #[inline(always)]
+    fn get_required(&self, key: &Q) -> Result<&V, NotFoundError<V>> {
+        self.get(key).ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self, key: &Q) -> Result<&mut V, NotFoundError<V>> {
+        self.get_mut(key).ok_or(NotFoundError::new())
+    }
+}
+
+impl<K: Borrow<Q> + Ord + Clone, V: Clone, Q: ?Sized + Ord> RequireGet<Q> for im::OrdMap<K, V> {
+    type V = V;
+
+    #[inline(always)]
+    fn get_required(&self, key: &Q) -> Result<&V, NotFoundError<V>> {
+        self.get(key).ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self, key: &Q) -> Result<&mut V, NotFoundError<V>> {
+        self.get_mut(key).ok_or(NotFoundError::new())
+    }
+}
+
+/// An extension trait for [`im::Vector`] that returns typed errors instead of `None` (feature `im`)
+///
+/// Lookups in `im::HashMap` and `im::OrdMap` are provided by [`RequireGet`].
+///
+/// # Examples
+///
+/// ```
+/// use im::{vector, OrdMap};
+/// use not_found_error::{NotFoundByKey, NotFoundError, RequireGet, VectorExt};
+///
+/// let mut history = vector![1, 2, 3];
+///
+/// assert_eq!(history.get_required(1), Ok(&2));
+/// assert_eq!(history.get_required(3), Err(NotFoundByKey::new(3)));
+/// *history.get_mut_required(0).unwrap() = 10;
+/// assert_eq!(history.front_required(), Ok(&10));
+/// assert_eq!(history.back_required(), Ok(&3));
+///
+/// let users = OrdMap::unit(1, "alice");
+/// assert_eq!(users.get_required(&1), Ok(&"alice"));
+/// assert_eq!(users.get_required(&2), Err(NotFoundError::new()));
+/// ```
+pub trait VectorExt<T> {
+    /// Returns the element at the given index, or an error that contains the index.
+    fn get_required(&self, index: usize) -> Result<&T, NotFoundByKey<T, usize>>;
+
+    /// Returns a mutable reference to the element at the given index, or an error that contains the index.
+    fn get_mut_required(&mut self, index: usize) -> Result<&mut T, NotFoundByKey<T, usize>>;
+
+    /// Returns the first element or an error if the vector is empty.
+    fn front_required(&self) -> Result<&T, NotFoundError<T>>;
+
+    /// Returns the last element or an error if the vector is empty.
+    fn back_required(&self) -> Result<&T, NotFoundError<T>>;
+}
+
+impl<T: Clone> VectorExt<T> for im::Vector<T> {
+    #[inline(always)]
+    fn get_required(&self, index: usize) -> Result<&T, NotFoundByKey<T, usize>> {
+        self.get(index).ok_or(NotFoundByKey::new(index))
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self, index: usize) -> Result<&mut T, NotFoundByKey<T, usize>> {
+        self.get_mut(index).ok_or(NotFoundByKey::new(index))
+    }
+
+    #[inline(always)]
+    fn front_required(&self) -> Result<&T, NotFoundError<T>> {
+        self.front().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn back_required(&self) -> Result<&T, NotFoundError<T>> {
+        self.back().ok_or(NotFoundError::new())
+    }
+}
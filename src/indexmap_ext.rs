@@ -0,0 +1,111 @@
+use std::hash::{BuildHasher, Hash};
+
+use indexmap::{Equivalent, IndexMap, IndexSet};
+
+use crate::{NotFoundByKey, NotFoundError, RequireGet, SetExt};
+
+impl<K, V, S: BuildHasher, Q: ?Sized + Hash + Equivalent<K>> RequireGet<Q> for IndexMap<K, V, S> {
+    type V = V;
+
+    #[inline(always)]
+    fn get_required(&self, key: &Q) -> Result<&V, NotFoundError<V>> {
+        self.get(key).ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self, key: &Q) -> Result<&mut V, NotFoundError<V>> {
+        self.get_mut(key).ok_or(NotFoundError::new())
+    }
+}
+
+/// An extension trait for [`IndexMap`] that returns typed errors for lookups by index (feature `indexmap`)
+///
+/// Lookups by key are provided by [`RequireGet`].
+///
+/// # Examples
+///
+/// ```
+/// use indexmap::IndexMap;
+/// use not_found_error::{IndexMapExt, NotFoundByKey, NotFoundError, RequireGet};
+///
+/// let config = IndexMap::from([("host", "localhost"), ("port", "8080")]);
+///
+/// assert_eq!(config.get_required("port"), Ok(&"8080"));
+/// assert_eq!(config.get_index_required(0), Ok((&"host", &"localhost")));
+/// assert_eq!(config.get_index_required(2), Err(NotFoundByKey::new(2)));
+/// assert_eq!(config.get_full_required("port"), Ok((1, &"port", &"8080")));
+/// assert_eq!(config.get_full_required("user"), Err(NotFoundError::new()));
+/// ```
+pub trait IndexMapExt<K, V> {
+    /// Returns the entry at the given index, or an error that contains the index.
+    fn get_index_required(&self, index: usize) -> Result<(&K, &V), NotFoundByKey<V, usize>>;
+
+    /// Returns the index, key and value of the entry for `key`, or an error if there is no such entry.
+    fn get_full_required<Q: ?Sized + Hash + Equivalent<K>>(&self, key: &Q) -> Result<(usize, &K, &V), NotFoundError<V>>;
+}
+
+impl<K, V, S: BuildHasher> IndexMapExt<K, V> for IndexMap<K, V, S> {
+    #[inline(always)]
+    fn get_index_required(&self, index: usize) -> Result<(&K, &V), NotFoundByKey<V, usize>> {
+        self.get_index(index).ok_or(NotFoundByKey::new(index))
+    }
+
+    #[inline(always)]
+    fn get_full_required<Q: ?Sized + Hash + Equivalent<K>>(&self, key: &Q) -> Result<(usize, &K, &V), NotFoundError<V>> {
+        self.get_full(key).ok_or(NotFoundError::new())
+    }
+}
+
+impl<T, S: BuildHasher, Q: ?Sized + Hash + Equivalent<T> + ToOwned> SetExt<Q> for IndexSet<T, S> {
+    type T = T;
+
+    #[inline(always)]
+    fn get_required(&self, value: &Q) -> Result<&T, NotFoundByKey<T, Q::Owned>> {
+        self.get(value)
+            .ok_or_else(|| NotFoundByKey::new(value.to_owned()))
+    }
+
+    /// Removes the value like [`IndexSet::swap_take`], so the last value takes its position.
+    #[inline(always)]
+    fn take_required(&mut self, value: &Q) -> Result<T, NotFoundByKey<T, Q::Owned>> {
+        self.swap_take(value)
+            .ok_or_else(|| NotFoundByKey::new(value.to_owned()))
+    }
+}
+
+/// An extension trait for [`IndexSet`] that returns typed errors for lookups by index (feature `indexmap`)
+///
+/// Lookups by value are provided by [`SetExt`].
+///
+/// # Examples
+///
+/// ```
+/// use indexmap::IndexSet;
+/// use not_found_error::{IndexSetExt, NotFoundByKey, NotFoundError};
+///
+/// let plugins = IndexSet::from(["auth", "cache"]);
+///
+/// assert_eq!(plugins.get_index_required(1), Ok(&"cache"));
+/// assert_eq!(plugins.get_index_required(2), Err(NotFoundByKey::new(2)));
+/// assert_eq!(plugins.get_full_required("cache"), Ok((1, &"cache")));
+/// assert_eq!(plugins.get_full_required("metrics"), Err(NotFoundError::new()));
+/// ```
+pub trait IndexSetExt<T> {
+    /// Returns the value at the given index, or an error that contains the index.
+    fn get_index_required(&self, index: usize) -> Result<&T, NotFoundByKey<T, usize>>;
+
+    /// Returns the index and the value in the set that is equal to the given one, or an error if there is no such value.
+    fn get_full_required<Q: ?Sized + Hash + Equivalent<T>>(&self, value: &Q) -> Result<(usize, &T), NotFoundError<T>>;
+}
+
+impl<T, S: BuildHasher> IndexSetExt<T> for IndexSet<T, S> {
+    #[inline(always)]
+    fn get_index_required(&self, index: usize) -> Result<&T, NotFoundByKey<T, usize>> {
+        self.get_index(index).ok_or(NotFoundByKey::new(index))
+    }
+
+    #[inline(always)]
+    fn get_full_required<Q: ?Sized + Hash + Equivalent<T>>(&self, value: &Q) -> Result<(usize, &T), NotFoundError<T>> {
+        self.get_full(value).ok_or(NotFoundError::new())
+    }
+}
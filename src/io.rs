@@ -0,0 +1,112 @@
+//! Helpers for searching readers with typed "not found" errors.
+//!
+//! The `_async` variants work on tokio's `AsyncBufRead` (feature `tokio`), and the `_async_std` variants work on async-std's `BufRead` (feature `async-std`).
+//!
+//! # Examples
+//!
+//! ```
+//! use not_found_error::io;
+//!
+//! let log = "INFO starting\nERROR disk full\nINFO retrying\n";
+//! assert_eq!(io::find_line_required(log.as_bytes(), |line| line.starts_with("ERROR")).unwrap(), "ERROR disk full");
+//! ```
+
+use std::io::{self, BufRead};
+
+use crate::{FindError, NotFoundError};
+
+/// A marker type for a line searched with [`find_line_required`]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default, Debug)]
+pub struct Line;
+
+/// A type alias for the error returned by [`find_line_required`]: either an IO error or `NotFoundError<Line>`
+pub type FindLineError = FindError<Line, io::Error>;
+
+/// Returns the first line of the reader that satisfies the predicate (without the line terminator).
+///
+/// Stops reading at the first matching line. An IO error (including invalid UTF-8) is returned as [`FindError::Other`].
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::io::{self, FindLineError};
+///
+/// let config = "# comment\nname = demo\n";
+/// let error = io::find_line_required(config.as_bytes(), |line| line.starts_with("version")).unwrap_err();
+/// assert!(matches!(error, FindLineError::NotFound(_)));
+///
+/// let error = io::find_line_required(&[0xff, b'\n'][..], |_| true).unwrap_err();
+/// assert!(matches!(error, FindLineError::Other(_)));
+/// ```
+pub fn find_line_required(reader: impl BufRead, mut f: impl FnMut(&str) -> bool) -> Result<String, FindLineError> {
+    for line in reader.lines() {
+        let line = line.map_err(FindError::Other)?;
+        if f(&line) {
+            return Ok(line);
+        }
+    }
+    Err(FindError::NotFound(NotFoundError::new()))
+}
+
+/// The async counterpart of [`find_line_required`] for tokio's `AsyncBufRead` (feature `tokio`)
+///
+/// Useful for tailing logs and parsing subprocess output asynchronously.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::io::{self, FindLineError};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let output = "Compiling demo\nListening on 127.0.0.1:3000\n";
+/// let line = io::find_line_required_async(output.as_bytes(), |line| line.starts_with("Listening")).await;
+/// assert_eq!(line.unwrap(), "Listening on 127.0.0.1:3000");
+///
+/// let error = io::find_line_required_async(output.as_bytes(), |line| line.starts_with("error")).await.unwrap_err();
+/// assert!(matches!(error, FindLineError::NotFound(_)));
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn find_line_required_async(reader: impl tokio::io::AsyncBufRead + Unpin, mut f: impl FnMut(&str) -> bool) -> Result<String, FindLineError> {
+    use tokio::io::AsyncBufReadExt;
+
+    let mut lines = reader.lines();
+    while let Some(line) = lines.next_line().await.map_err(FindError::Other)? {
+        if f(&line) {
+            return Ok(line);
+        }
+    }
+    Err(FindError::NotFound(NotFoundError::new()))
+}
+
+/// The async counterpart of [`find_line_required`] for async-std's `BufRead` (feature `async-std`)
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::io::{self, FindLineError};
+///
+/// async_std::task::block_on(async {
+///     let output = "Compiling demo\nListening on 127.0.0.1:3000\n";
+///     let line = io::find_line_required_async_std(output.as_bytes(), |line| line.starts_with("Listening")).await;
+///     assert_eq!(line.unwrap(), "Listening on 127.0.0.1:3000");
+///
+///     let error = io::find_line_required_async_std(output.as_bytes(), |line| line.starts_with("error")).await.unwrap_err();
+///     assert!(matches!(error, FindLineError::NotFound(_)));
+/// });
+/// ```
+#[cfg(feature = "async-std")]
+pub async fn find_line_required_async_std(reader: impl async_std::io::BufRead + Unpin, mut f: impl FnMut(&str) -> bool) -> Result<String, FindLineError> {
+    use async_std::io::prelude::BufReadExt;
+    use async_std::stream::StreamExt;
+
+    let mut lines = reader.lines();
+    while let Some(line) = lines.next().await {
+        let line = line.map_err(FindError::Other)?;
+        if f(&line) {
+            return Ok(line);
+        }
+    }
+    Err(FindError::NotFound(NotFoundError::new()))
+}
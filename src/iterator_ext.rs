@@ -0,0 +1,267 @@
+use crate::report::NotFoundReport;
+use crate::{AnyNotFound, DynNotFoundError, IgnoreNotFound, NotFoundError};
+
+/// An extension trait for iterators that returns `NotFoundError` instead of `None`
+pub trait IteratorExt: Iterator {
+    /// Collects an iterator of `Option<T>` into `Vec<T>`, returning an error on the first `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{IteratorExt, NotFoundError};
+    ///
+    /// assert_eq!([Some(1), Some(2)].into_iter().require_all(), Ok(vec![1, 2]));
+    /// assert_eq!([Some(1), None].into_iter().require_all(), Err(NotFoundError::<i32>::new()));
+    /// ```
+    fn require_all<T>(self) -> Result<Vec<T>, NotFoundError<T>>
+    where
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.map(|option| option.ok_or(NotFoundError::new()))
+            .collect()
+    }
+
+    /// Collects an iterator of `Option<T>` into `Vec<T>`, returning a report with the indices of all `None`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::IteratorExt;
+    ///
+    /// assert_eq!([Some(1), Some(2)].into_iter().require_all_indexed(), Ok(vec![1, 2]));
+    ///
+    /// let report = [None, Some(1), None].into_iter().require_all_indexed().unwrap_err();
+    /// assert_eq!(report.to_string(), "2 items not found:\n- i32 not found by key 0\n- i32 not found by key 2");
+    /// ```
+    fn require_all_indexed<T>(self) -> Result<Vec<T>, NotFoundReport>
+    where
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        let mut items = Vec::new();
+        let mut report = NotFoundReport::new();
+        for (index, option) in self.enumerate() {
+            match option {
+                Some(item) => items.push(item),
+                None => report.push(DynNotFoundError::with_key::<T>(&index)),
+            }
+        }
+        if report.is_empty() {
+            Ok(items)
+        } else {
+            Err(report)
+        }
+    }
+
+    /// Returns the next element of the iterator or an error if the iterator is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{IteratorExt, NotFoundError};
+    ///
+    /// let mut iter = [1].into_iter();
+    /// assert_eq!(iter.require_next(), Ok(1));
+    /// assert_eq!(iter.require_next(), Err(NotFoundError::new()));
+    /// ```
+    fn require_next(&mut self) -> Result<Self::Item, NotFoundError<Self::Item>> {
+        self.next().ok_or(NotFoundError::new())
+    }
+
+    /// Returns the `n`-th element of the iterator (zero-based) or an error if the iterator is too short.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{IteratorExt, NotFoundError};
+    ///
+    /// assert_eq!([1, 2, 3].into_iter().require_nth(2), Ok(3));
+    /// assert_eq!([1, 2, 3].into_iter().require_nth(3), Err(NotFoundError::new()));
+    /// ```
+    fn require_nth(&mut self, n: usize) -> Result<Self::Item, NotFoundError<Self::Item>> {
+        self.nth(n).ok_or(NotFoundError::new())
+    }
+
+    /// Returns the last element of the iterator or an error if the iterator is empty.
+    ///
+    /// This consumes the whole iterator; use [`IteratorExt::require_next_back`] on a [`DoubleEndedIterator`] to take the last element without walking over the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{IteratorExt, NotFoundError};
+    ///
+    /// assert_eq!([1, 2, 3].into_iter().require_last(), Ok(3));
+    /// assert_eq!(std::iter::empty::<i32>().require_last(), Err(NotFoundError::new()));
+    /// ```
+    fn require_last(self) -> Result<Self::Item, NotFoundError<Self::Item>>
+    where
+        Self: Sized,
+    {
+        self.last().ok_or(NotFoundError::new())
+    }
+
+    /// Returns the next element from the back of a [`DoubleEndedIterator`] or an error if the iterator is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{IteratorExt, NotFoundError};
+    ///
+    /// let entries = vec!["old", "new"];
+    /// assert_eq!(entries.iter().require_next_back(), Ok(&"new"));
+    /// assert_eq!(Vec::<i32>::new().iter().require_next_back(), Err(NotFoundError::new()));
+    /// ```
+    fn require_next_back(&mut self) -> Result<Self::Item, NotFoundError<Self::Item>>
+    where
+        Self: DoubleEndedIterator,
+    {
+        self.next_back().ok_or(NotFoundError::new())
+    }
+
+    /// Returns the minimum element of the iterator or an error if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{IteratorExt, NotFoundError};
+    ///
+    /// assert_eq!([3, 1, 2].into_iter().require_min(), Ok(1));
+    /// assert_eq!(std::iter::empty::<i32>().require_min(), Err(NotFoundError::new()));
+    /// ```
+    fn require_min(self) -> Result<Self::Item, NotFoundError<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        self.min().ok_or(NotFoundError::new())
+    }
+
+    /// Returns the maximum element of the iterator or an error if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{IteratorExt, NotFoundError};
+    ///
+    /// assert_eq!([3, 1, 2].into_iter().require_max(), Ok(3));
+    /// assert_eq!(std::iter::empty::<i32>().require_max(), Err(NotFoundError::new()));
+    /// ```
+    fn require_max(self) -> Result<Self::Item, NotFoundError<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        self.max().ok_or(NotFoundError::new())
+    }
+
+    /// Returns the element that gives the minimum value from the specified function or an error if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{IteratorExt, NotFoundError};
+    ///
+    /// assert_eq!(["ccc", "a", "bb"].into_iter().require_min_by_key(|s| s.len()), Ok("a"));
+    /// assert_eq!(std::iter::empty::<&str>().require_min_by_key(|s| s.len()), Err(NotFoundError::new()));
+    /// ```
+    fn require_min_by_key<B: Ord>(self, f: impl FnMut(&Self::Item) -> B) -> Result<Self::Item, NotFoundError<Self::Item>>
+    where
+        Self: Sized,
+    {
+        self.min_by_key(f).ok_or(NotFoundError::new())
+    }
+
+    /// Returns the element that gives the maximum value from the specified function or an error if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{IteratorExt, NotFoundError};
+    ///
+    /// assert_eq!(["ccc", "a", "bb"].into_iter().require_max_by_key(|s| s.len()), Ok("ccc"));
+    /// assert_eq!(std::iter::empty::<&str>().require_max_by_key(|s| s.len()), Err(NotFoundError::new()));
+    /// ```
+    fn require_max_by_key<B: Ord>(self, f: impl FnMut(&Self::Item) -> B) -> Result<Self::Item, NotFoundError<Self::Item>>
+    where
+        Self: Sized,
+    {
+        self.max_by_key(f).ok_or(NotFoundError::new())
+    }
+
+    /// Returns the index of the first element that satisfies the predicate or an error if there is no such element.
+    ///
+    /// The error is labeled by the item type, not by `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{IteratorExt, NotFoundError};
+    ///
+    /// assert_eq!([1, 2, 3].into_iter().require_position(|n| n == 2), Ok(1));
+    /// assert_eq!([1, 2, 3].into_iter().require_position(|n| n == 4), Err(NotFoundError::<i32>::new()));
+    /// ```
+    fn require_position(&mut self, predicate: impl FnMut(Self::Item) -> bool) -> Result<usize, NotFoundError<Self::Item>>
+    where
+        Self: Sized,
+    {
+        self.position(predicate).ok_or(NotFoundError::new())
+    }
+
+    /// Skips the "not found" errors (as reported by [`AnyNotFound`]) and yields all other items and errors unchanged.
+    ///
+    /// Useful for "resolve these IDs, skip the missing ones, fail on real errors" pipelines.
+    ///
+    /// Boxed errors are skipped if their [`source`](std::error::Error::source) chain contains a "not found" error (see the impl of [`AnyNotFound`] for `dyn Error`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use not_found_error::IteratorExt;
+    ///
+    /// let results = vec![
+    ///     Ok(1),
+    ///     Err(io::Error::from(io::ErrorKind::NotFound)),
+    ///     Ok(2),
+    ///     Err(io::Error::from(io::ErrorKind::PermissionDenied)),
+    /// ];
+    ///
+    /// let kinds: Vec<_> = results
+    ///     .into_iter()
+    ///     .ignore_not_found()
+    ///     .map(|result| result.map_err(|error| error.kind()))
+    ///     .collect();
+    /// assert_eq!(kinds, vec![Ok(1), Ok(2), Err(io::ErrorKind::PermissionDenied)]);
+    /// ```
+    ///
+    /// ```
+    /// use std::error::Error;
+    /// use std::io;
+    /// use not_found_error::{IteratorExt, NotFoundError};
+    ///
+    /// # #[derive(Debug)]
+    /// # struct User;
+    /// let results: Vec<Result<u32, Box<dyn Error + Send + Sync>>> = vec![
+    ///     Ok(1),
+    ///     Err(Box::new(NotFoundError::<User>::new())),
+    ///     Err(Box::new(NotFoundError::<User>::new().with_source(io::Error::from(io::ErrorKind::TimedOut)))),
+    ///     Err(Box::new(io::Error::other(NotFoundError::<User>::new()))),
+    ///     Err("connection reset".into()),
+    /// ];
+    ///
+    /// let results: Vec<_> = results
+    ///     .into_iter()
+    ///     .ignore_not_found()
+    ///     .map(|result| result.map_err(|error| error.to_string()))
+    ///     .collect();
+    /// assert_eq!(results, vec![Ok(1), Err("connection reset".to_string())]);
+    /// ```
+    fn ignore_not_found<T, E: AnyNotFound>(self) -> IgnoreNotFound<Self>
+    where
+        Self: Iterator<Item = Result<T, E>> + Sized,
+    {
+        IgnoreNotFound::new(self)
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
@@ -21,6 +21,44 @@
 //! * [x] Generic `NotFoundError<T>` type
 //! * [x] Conversion functions and traits to transform `Option<T>` into `Result<T, NotFoundError<T>>`
 //! * [x] Conversion functions and traits to transform `Option<T>` into `Result<T, NotFoundError<AnotherType>>`
+//! * [x] Type-erased `DynNotFoundError` and `NotFoundReport` for collecting multiple missing items
+//! * [x] Iterator extensions that return `NotFoundError` instead of `None`
+//! * [x] Map extensions that report every missing key
+//! * [x] Slice extensions that report the out-of-range index
+//! * [x] String extensions that record the missing pattern
+//! * [x] Extensions for `Vec`, `VecDeque`, `BinaryHeap` and the std set types
+//! * [x] Conversion of tuples of `Option`s into `Result`s that identify the missing elements
+//! * [x] Sibling lookup errors: `AmbiguousError<T>`, `GoneError<T>`, `ExpiredError<T>`
+//! * [x] "Did you mean" suggestions for failed lookups by name (feature `suggestions`)
+//! * [x] Stable error identifiers via the `ErrorId` trait (surfaced through serde and miette behind the `serde` and `miette` features)
+//! * [x] Runtime registry that deserializes typed errors by their identifiers (feature `serde-registry`)
+//! * [x] `Required<T>` newtype that guarantees presence of the value
+//! * [x] Fluent builder for detailed errors with lookup keys and container description
+//! * [x] Lazily evaluated fallbacks via `first_found` and `OrFind` (and concurrent ones via `first_found_async` behind the `futures` feature)
+//! * [x] Conversion of "not found" errors back to `Option<T>` via `Optional` and `TryOptional`
+//! * [x] Extensions for `IndexMap` and `IndexSet` (feature `indexmap`)
+//! * [x] Extensions for `DashMap` (feature `dashmap`)
+//! * [x] Keyed errors for stale handles in `slotmap` and `generational-arena` (features `slotmap` and `generational-arena`)
+//! * [x] Extensions for the persistent collections of `im` (feature `im`)
+//! * [x] Extensions for `lru` and `moka` caches that fill the cache from a `Find` source on a miss (features `lru` and `moka`)
+//! * [x] Keyed errors for node and edge lookups in `petgraph` (feature `petgraph`)
+//! * [x] Keyed errors for component, query and asset lookups in bevy (feature `bevy`, which requires Rust 1.95)
+//! * [x] Keyed errors for component and query lookups in `hecs` (feature `hecs`, which requires Rust 1.89)
+//! * [x] Keyed errors for component lookups in `specs` storages (feature `specs`)
+//! * [x] `VecExt` for `SmallVec` and `ArrayVec` (features `smallvec` and `arrayvec`)
+//! * [x] `require` for futures that resolve to `Option<T>` and search extensions for streams (feature `futures`)
+//! * [x] Deadlines for async lookups via `within_or_not_found` (feature `tokio`) and `within_or_not_found_async_std` (feature `async-std`)
+//! * [x] Waiting helpers for tokio channels (feature `tokio`)
+//! * [x] Conversion between `FindAsync` sources and tower services (feature `tower`)
+//! * [x] Parallel search over rayon iterators (feature `rayon`)
+//! * [x] Checked access to `OnceLock` and `OnceCell` (plus the `once_cell` crate behind the `once_cell` feature), and `Require` for `LazyLock<Option<T>>` and `LazyCell<Option<T>>`
+//! * [x] Environment variable helpers with typed markers (`env` module)
+//! * [x] Command-line argument helpers (`args` module) and `ArgMatches` extensions (feature `clap`)
+//! * [x] Ancestor-search helpers for files like `Cargo.toml` (`fs` module, with async variants behind the `tokio` and `async-std` features)
+//! * [x] Byte pattern search in slices and readers (`bytes` module, accelerated by the `memchr` feature)
+//! * [x] Line search in readers that separates IO errors from "not found" (`io` module)
+//! * [x] Socket address resolution that treats an empty result as "not found" and network interface lookup (`net` module, the latter behind the `if-addrs` feature)
+//! * [x] Prelude module that re-exports all extension traits
 //!
 //! # Examples
 //!
@@ -50,14 +88,210 @@
 //! ```
 
 use std::any::type_name;
+use std::cmp::Ordering;
 use std::error::Error;
 use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 
+mod any_ext;
+mod any_not_found;
+#[cfg(feature = "arrayvec")]
+mod arrayvec_ext;
+#[cfg(feature = "bevy")]
+mod bevy_ext;
+mod binary_heap_ext;
+#[cfg(feature = "clap")]
+mod clap_ext;
+mod context_not_found_error;
+#[cfg(feature = "dashmap")]
+mod dashmap_ext;
+mod detailed_not_found_error;
+mod dyn_not_found_error;
+mod entry_ext;
+mod error_id;
+mod exact_size_iterator_ext;
+mod expect_found;
+mod find;
+mod find_error;
+mod first_found;
+mod flatten_require;
+#[cfg(feature = "futures")]
+mod future_require_ext;
+#[cfg(feature = "generational-arena")]
+mod generational_arena_ext;
+mod get_many_mut_error;
+#[cfg(feature = "hecs")]
+mod hecs_ext;
+mod ignore_not_found;
+#[cfg(feature = "im")]
+mod im_ext;
+#[cfg(feature = "indexmap")]
+mod indexmap_ext;
+mod iterator_ext;
+mod locate_error;
+mod locate_ext;
+#[cfg(feature = "lru")]
+mod lru_ext;
+mod map_ext;
+mod map_not_found;
+mod missing_keys_error;
+#[cfg(feature = "moka")]
+mod moka_ext;
+mod not_enough_items_error;
+mod not_found_by_key;
+#[cfg(feature = "once_cell")]
+mod once_cell_ext;
+mod once_ext;
+mod option_mut_ext;
+mod optional;
+mod or_not_found;
+#[cfg(feature = "rayon")]
+mod par_locate;
+mod path_ext;
+mod peekable_ext;
+#[cfg(feature = "petgraph")]
+mod petgraph_ext;
+mod receive_error;
+mod receiver_ext;
+mod require_all;
+mod require_get;
+mod require_into;
+mod require_poll;
+mod require_ref;
+mod require_transpose;
+mod required;
+#[cfg(feature = "serde-registry")]
+mod serde_registry;
+mod set_ext;
+mod slice_require;
+#[cfg(feature = "slotmap")]
+mod slotmap_ext;
+#[cfg(feature = "smallvec")]
+mod smallvec_ext;
+#[cfg(feature = "specs")]
+mod specs_ext;
+mod static_label;
+mod str_require;
+#[cfg(feature = "futures")]
+mod stream_require_ext;
+#[cfg(feature = "suggestions")]
+mod suggestions;
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+mod timed_out_not_found;
+#[cfg(feature = "tokio")]
+mod tokio_ext;
+#[cfg(feature = "tower")]
+mod tower_ext;
+mod unwrap_found;
+mod vec_deque_ext;
+mod vec_ext;
+mod weak_ext;
+
+pub use any_ext::*;
+pub use any_not_found::*;
+#[cfg(feature = "bevy")]
+pub use bevy_ext::*;
+pub use binary_heap_ext::*;
+#[cfg(feature = "clap")]
+pub use clap_ext::*;
+pub use context_not_found_error::*;
+#[cfg(feature = "dashmap")]
+pub use dashmap_ext::*;
+pub use detailed_not_found_error::*;
+pub use dyn_not_found_error::*;
+pub use entry_ext::*;
+pub use error_id::*;
+pub use exact_size_iterator_ext::*;
+pub use expect_found::*;
+pub use find::*;
+pub use find_error::*;
+pub use first_found::*;
+pub use flatten_require::*;
+#[cfg(feature = "futures")]
+pub use future_require_ext::*;
+#[cfg(feature = "generational-arena")]
+pub use generational_arena_ext::*;
+pub use get_many_mut_error::*;
+#[cfg(feature = "hecs")]
+pub use hecs_ext::*;
+pub use ignore_not_found::*;
+#[cfg(feature = "im")]
+pub use im_ext::*;
+#[cfg(feature = "indexmap")]
+pub use indexmap_ext::*;
+pub use iterator_ext::*;
+pub use locate_error::*;
+pub use locate_ext::*;
+#[cfg(feature = "lru")]
+pub use lru_ext::*;
+pub use map_ext::*;
+pub use map_not_found::*;
+pub use missing_keys_error::*;
+#[cfg(feature = "moka")]
+pub use moka_ext::*;
+pub use not_enough_items_error::*;
+pub use not_found_by_key::*;
+pub use once_ext::*;
+pub use option_mut_ext::*;
+pub use optional::*;
+pub use or_not_found::*;
+#[cfg(feature = "rayon")]
+pub use par_locate::*;
+pub use path_ext::*;
+pub use peekable_ext::*;
+#[cfg(feature = "petgraph")]
+pub use petgraph_ext::*;
+pub use receive_error::*;
+pub use receiver_ext::*;
+pub use require_all::*;
+pub use require_get::*;
+pub use require_into::*;
+pub use require_poll::*;
+pub use require_ref::*;
+pub use require_transpose::*;
+pub use required::*;
+#[cfg(feature = "serde-registry")]
+pub use serde_registry::*;
+pub use set_ext::*;
+pub use slice_require::*;
+#[cfg(feature = "slotmap")]
+pub use slotmap_ext::*;
+#[cfg(feature = "specs")]
+pub use specs_ext::*;
+pub use static_label::*;
+pub use str_require::*;
+#[cfg(feature = "futures")]
+pub use stream_require_ext::*;
+#[cfg(feature = "suggestions")]
+pub use suggestions::*;
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+pub use timed_out_not_found::*;
+#[cfg(feature = "tokio")]
+pub use tokio_ext::*;
+#[cfg(feature = "tower")]
+pub use tower_ext::*;
+pub use unwrap_found::*;
+pub use vec_deque_ext::*;
+pub use vec_ext::*;
+pub use weak_ext::*;
+
+pub mod args;
+pub mod bytes;
+pub mod env;
+pub mod fs;
+pub mod io;
+pub mod lookup_errors;
+pub mod net;
+pub mod prelude;
+pub mod report;
+
 /// Represents an error indicating that a value was not found.
 ///
 /// This struct is generic over the type `T` that was not found.
 ///
+/// `T` may be unsized (e.g. `NotFoundError<str>`, `NotFoundError<[u8]>` or `NotFoundError<dyn Trait>`). The trait impls don't require `T` to implement the corresponding traits, because `T` is only a label.
+///
 /// # Examples
 ///
 /// ```
@@ -65,11 +299,13 @@ use std::marker::PhantomData;
 ///
 /// let error: NotFoundError<i32> = NotFoundError::new();
 /// assert_eq!(error.to_string(), "i32 not found");
+///
+/// let error: NotFoundError<str> = NotFoundError::new();
+/// assert_eq!(error.to_string(), "str not found");
 /// ```
-#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
-pub struct NotFoundError<T>(pub PhantomData<T>);
+pub struct NotFoundError<T: ?Sized>(pub PhantomData<T>);
 
-impl<T> NotFoundError<T> {
+impl<T: ?Sized> NotFoundError<T> {
     /// Creates a new `NotFoundError`.
     ///
     /// # Examples
@@ -82,7 +318,9 @@ impl<T> NotFoundError<T> {
     pub fn new() -> Self {
         Self(PhantomData)
     }
+}
 
+impl<T> NotFoundError<T> {
     /// Convenience method to automatically convert the error to a result.
     ///
     /// # Examples
@@ -98,23 +336,128 @@ impl<T> NotFoundError<T> {
     }
 }
 
-impl<T> Default for NotFoundError<T> {
+impl<T: ?Sized> Default for NotFoundError<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T> std::fmt::Display for NotFoundError<T> {
+impl<T: ?Sized> Clone for NotFoundError<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for NotFoundError<T> {}
+
+impl<T: ?Sized> PartialEq for NotFoundError<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T: ?Sized> Eq for NotFoundError<T> {}
+
+impl<T: ?Sized> PartialOrd for NotFoundError<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ?Sized> Ord for NotFoundError<T> {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<T: ?Sized> Hash for NotFoundError<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T: ?Sized> Debug for NotFoundError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("NotFoundError").field(&self.0).finish()
+    }
+}
+
+impl<T: ?Sized> std::fmt::Display for NotFoundError<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} not found", type_name::<T>())
     }
 }
 
-impl<T: Debug> Error for NotFoundError<T> {}
+impl<T: ?Sized> Error for NotFoundError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        mark_not_found(None)
+    }
+}
+
+impl<T: ?Sized> AnyNotFound for NotFoundError<T> {
+    fn is_not_found(&self) -> bool {
+        true
+    }
+}
+
+impl<T: ?Sized> NotFoundError<&T> {
+    /// Changes the type parameter from `&T` to `T`.
+    ///
+    /// Useful for storing errors produced against borrowed items in `'static` error enums.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundError, Require};
+    ///
+    /// let names = vec![String::from("alice")];
+    /// let error: NotFoundError<&String> = names.get(1).require().unwrap_err();
+    /// let error: NotFoundError<String> = error.into_owned_type();
+    /// ```
+    pub fn into_owned_type(self) -> NotFoundError<T> {
+        NotFoundError::new()
+    }
+}
+
+impl<T: ?Sized> NotFoundError<&mut T> {
+    /// Changes the type parameter from `&mut T` to `T`.
+    pub fn into_owned_type(self) -> NotFoundError<T> {
+        NotFoundError::new()
+    }
+}
+
+/// Allows `?` to convert `NotFoundError<&T>` into `NotFoundError<T>`
+///
+/// ```
+/// use not_found_error::{NotFoundError, Require};
+///
+/// fn first_name(names: &[String]) -> Result<usize, NotFoundError<String>> {
+///     Ok(names.first().require()?.len())
+/// }
+///
+/// assert_eq!(first_name(&[]), Err(NotFoundError::new()));
+/// ```
+impl<T: ?Sized> From<NotFoundError<&T>> for NotFoundError<T> {
+    fn from(error: NotFoundError<&T>) -> Self {
+        error.into_owned_type()
+    }
+}
+
+impl<T: ?Sized> From<NotFoundError<&mut T>> for NotFoundError<T> {
+    fn from(error: NotFoundError<&mut T>) -> Self {
+        error.into_owned_type()
+    }
+}
 
 /// A type alias for `Result<T, NotFoundError<T>>`
 pub type Result<T> = core::result::Result<T, NotFoundError<T>>;
 
+/// A type alias for `Result<T, NotFoundError<T>>` that doesn't shadow [`std::result::Result`] when imported
+pub type NotFoundResult<T> = core::result::Result<T, NotFoundError<T>>;
+
+/// A type alias for `Result<T, FindError<T, E>>`
+pub type FindResult<T, E> = core::result::Result<T, FindError<T, E>>;
+
 /// Converts `Option<T>` to `Result<T, NotFoundError<T>>`
 ///
 /// # Examples
@@ -155,12 +498,14 @@ pub fn require<T>(option: Option<T>) -> Result<T> {
 /// - [`require`]: Function to convert `Option<T>` to `Result<T, NotFoundError<T>>`
 /// - [`OkOrNotFound`]: Trait for converting `Option<T>` to `Result<T, NotFoundError<AnotherType>>`
 #[inline(always)]
-pub fn not_found<AnotherType>() -> NotFoundError<AnotherType> {
+pub fn not_found<AnotherType: ?Sized>() -> NotFoundError<AnotherType> {
     NotFoundError(PhantomData)
 }
 
 /// An extension trait for `Option<T>` to convert it to `Result<T, NotFoundError<T>>`
 ///
+/// Also implemented for `&Option<T>` and `&mut Option<T>`: the error is still typed by `T` (not `&T`), so it unifies with the error of the owned case.
+///
 /// # Examples
 ///
 /// ```
@@ -169,6 +514,16 @@ pub fn not_found<AnotherType>() -> NotFoundError<AnotherType> {
 /// let item = items.first().require();
 /// ```
 ///
+/// ```
+/// # use not_found_error::{NotFoundError, Require};
+/// let mut name = Some(String::from("alice"));
+/// let result: Result<&String, NotFoundError<String>> = (&name).require();
+/// assert_eq!(result, Ok(&String::from("alice")));
+///
+/// (&mut name).require().unwrap().push_str("-admin");
+/// assert_eq!(name.as_deref(), Some("alice-admin"));
+/// ```
+///
 /// # See also
 ///
 /// - [`require`]: Function to convert `Option<T>` to `Result<T, NotFoundError<T>>`
@@ -176,11 +531,15 @@ pub fn not_found<AnotherType>() -> NotFoundError<AnotherType> {
 pub trait Require {
     type T;
 
-    fn require(self) -> Result<Self::T>;
+    /// The type parameter of the returned [`NotFoundError`]
+    type Label;
+
+    fn require(self) -> core::result::Result<Self::T, NotFoundError<Self::Label>>;
 }
 
 impl<T> Require for Option<T> {
     type T = T;
+    type Label = T;
 
     #[inline(always)]
     fn require(self) -> Result<Self::T> {
@@ -188,6 +547,26 @@ impl<T> Require for Option<T> {
     }
 }
 
+impl<'a, T> Require for &'a Option<T> {
+    type T = &'a T;
+    type Label = T;
+
+    #[inline(always)]
+    fn require(self) -> core::result::Result<Self::T, NotFoundError<Self::Label>> {
+        self.as_ref().ok_or(NotFoundError(PhantomData))
+    }
+}
+
+impl<'a, T> Require for &'a mut Option<T> {
+    type T = &'a mut T;
+    type Label = T;
+
+    #[inline(always)]
+    fn require(self) -> core::result::Result<Self::T, NotFoundError<Self::Label>> {
+        self.as_mut().ok_or(NotFoundError(PhantomData))
+    }
+}
+
 /// An extension trait for `Option<T>` to convert it to `Result<T, NotFoundError<AnotherType>>`
 ///
 /// Useful in places where you need `NotFoundError<AnotherType>` instead of `NotFoundError<T>`.
@@ -211,14 +590,14 @@ impl<T> Require for Option<T> {
 pub trait OkOrNotFound {
     type T;
 
-    fn ok_or_not_found<B>(self) -> core::result::Result<Self::T, NotFoundError<B>>;
+    fn ok_or_not_found<B: ?Sized>(self) -> core::result::Result<Self::T, NotFoundError<B>>;
 }
 
 impl<T> OkOrNotFound for Option<T> {
     type T = T;
 
     #[inline(always)]
-    fn ok_or_not_found<B>(self) -> core::result::Result<Self::T, NotFoundError<B>> {
+    fn ok_or_not_found<B: ?Sized>(self) -> core::result::Result<Self::T, NotFoundError<B>> {
         self.ok_or(NotFoundError(PhantomData))
     }
 }
@@ -245,3 +624,228 @@ impl<T> OkOrNotFound for Option<T> {
 pub fn locate<'a, T>(iter: impl IntoIterator<Item = &'a T>, f: impl FnMut(&&T) -> bool) -> core::result::Result<&'a T, NotFoundError<T>> {
     iter.into_iter().find(f).ok_or_not_found()
 }
+
+/// Searches an iterator for the only element that satisfies a given predicate and returns a reference to it.
+///
+/// Unlike [`locate`], this function consumes the whole iterator and returns [`LocateError::Ambiguous`] if more than one element matches.
+///
+/// # Examples
+///
+/// ```
+/// # use not_found_error::{locate_unique, LocateError, NotFoundError};
+///
+/// let numbers = &[1, 2, 3, 4, 5];
+///
+/// assert_eq!(locate_unique(numbers, |&&n| n == 3), Ok(&3));
+/// assert_eq!(locate_unique(numbers, |&&n| n % 2 == 0), Err(LocateError::Ambiguous { count: 2 }));
+/// assert_eq!(locate_unique(numbers, |&&n| n > 10), Err(LocateError::NotFound(NotFoundError::new())));
+/// ```
+pub fn locate_unique<'a, T>(iter: impl IntoIterator<Item = &'a T>, mut f: impl FnMut(&&T) -> bool) -> core::result::Result<&'a T, LocateError<T>> {
+    let mut matches = iter.into_iter().filter(|item| f(item));
+    let item = matches.next().ok_or_not_found::<T>()?;
+    match matches.count() {
+        0 => Ok(item),
+        rest => Err(LocateError::Ambiguous {
+            count: rest + 1,
+        }),
+    }
+}
+
+/// Searches an iterator for an element that satisfies a given predicate and returns a mutable reference to it.
+///
+/// # Examples
+///
+/// ```
+/// # use not_found_error::{locate_mut, NotFoundError};
+///
+/// let mut numbers = vec![1, 2, 3];
+///
+/// *locate_mut(&mut numbers, |n| **n == 2).unwrap() = 20;
+/// assert_eq!(numbers, vec![1, 20, 3]);
+///
+/// assert_eq!(locate_mut(&mut numbers, |n| **n > 100), Err(NotFoundError::new()));
+/// ```
+pub fn locate_mut<'a, T>(iter: impl IntoIterator<Item = &'a mut T>, f: impl FnMut(&&mut T) -> bool) -> core::result::Result<&'a mut T, NotFoundError<T>> {
+    iter.into_iter().find(f).ok_or_not_found()
+}
+
+/// Applies a function to the elements of an iterator and returns the first non-`None` result.
+///
+/// This function is different from [`Iterator::find_map`] because it returns `Result<U, NotFoundError<U>>` (not `Option<U>`).
+///
+/// # Examples
+///
+/// ```
+/// # use not_found_error::{locate_map, NotFoundError};
+///
+/// let strings = ["one", "2", "three"];
+///
+/// assert_eq!(locate_map(strings, |s| s.parse::<u32>().ok()), Ok(2));
+/// assert_eq!(locate_map(["one"], |s| s.parse::<u32>().ok()), Err(NotFoundError::new()));
+/// ```
+pub fn locate_map<T, U>(iter: impl IntoIterator<Item = T>, f: impl FnMut(T) -> Option<U>) -> core::result::Result<U, NotFoundError<U>> {
+    iter.into_iter().find_map(f).ok_or_not_found()
+}
+
+/// Searches an iterator for an element that satisfies a given predicate and returns its index along with a reference to it.
+///
+/// Useful when the element needs to be removed or replaced afterwards.
+///
+/// # Examples
+///
+/// ```
+/// # use not_found_error::{locate_index, NotFoundError};
+///
+/// let mut numbers = vec![1, 2, 3];
+///
+/// let (index, _) = locate_index(&numbers, |&&n| n == 2).unwrap();
+/// numbers.remove(index);
+/// assert_eq!(numbers, vec![1, 3]);
+///
+/// assert_eq!(locate_index(&numbers, |&&n| n == 2), Err(NotFoundError::new()));
+/// ```
+pub fn locate_index<'a, T>(iter: impl IntoIterator<Item = &'a T>, mut f: impl FnMut(&&T) -> bool) -> core::result::Result<(usize, &'a T), NotFoundError<T>> {
+    iter.into_iter()
+        .enumerate()
+        .find(|(_, item)| f(item))
+        .ok_or_not_found()
+}
+
+/// Searches a sorted slice for an element equal to `key` using binary search.
+///
+/// Unlike [`slice::binary_search`], this function discards the insertion index and returns an error that contains the key.
+///
+/// # Examples
+///
+/// ```
+/// # use not_found_error::{locate_sorted, NotFoundByKey};
+///
+/// let numbers = [1, 3, 5, 7];
+///
+/// assert_eq!(locate_sorted(&numbers, &5), Ok(&5));
+/// assert_eq!(locate_sorted(&numbers, &4), Err(NotFoundByKey::new(4)));
+/// ```
+pub fn locate_sorted<'a, T: Ord + Clone>(slice: &'a [T], key: &T) -> core::result::Result<&'a T, NotFoundByKey<T, T>> {
+    match slice.binary_search(key) {
+        Ok(index) => Ok(&slice[index]),
+        Err(_) => Err(NotFoundByKey::new(key.clone())),
+    }
+}
+
+/// Searches a slice sorted by the key extracted with `f` for an element with the key equal to `key` using binary search.
+///
+/// # Examples
+///
+/// ```
+/// # use not_found_error::{locate_sorted_by_key, NotFoundByKey};
+///
+/// let users = [(1, "alice"), (3, "bob"), (5, "carol")];
+///
+/// assert_eq!(locate_sorted_by_key(&users, &3, |(id, _)| *id), Ok(&(3, "bob")));
+/// assert_eq!(locate_sorted_by_key(&users, &4, |(id, _)| *id), Err(NotFoundByKey::new(4)));
+/// ```
+pub fn locate_sorted_by_key<'a, T, K: Ord + Clone>(slice: &'a [T], key: &K, f: impl FnMut(&T) -> K) -> core::result::Result<&'a T, NotFoundByKey<T, K>> {
+    match slice.binary_search_by_key(key, f) {
+        Ok(index) => Ok(&slice[index]),
+        Err(_) => Err(NotFoundByKey::new(key.clone())),
+    }
+}
+
+/// Searches an iterator for an element whose extracted key is equal to `key` and returns a reference to it.
+///
+/// The error contains the searched key.
+///
+/// # Examples
+///
+/// ```
+/// # use not_found_error::{locate_by_key, NotFoundByKey};
+///
+/// # #[derive(PartialEq, Debug)]
+/// struct User {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// let users = vec![User { id: 1, name: "alice".into() }, User { id: 2, name: "bob".into() }];
+///
+/// assert_eq!(locate_by_key(&users, 2, |user| user.id), Ok(&users[1]));
+/// assert_eq!(locate_by_key(&users, "bob", |user| user.name.as_str()), Ok(&users[1]));
+/// assert_eq!(locate_by_key(&users, 3, |user| user.id), Err(NotFoundByKey::new(3)));
+/// ```
+pub fn locate_by_key<'a, T, K, Q: PartialEq<K>>(iter: impl IntoIterator<Item = &'a T>, key: K, mut key_fn: impl FnMut(&'a T) -> Q) -> core::result::Result<&'a T, NotFoundByKey<T, K>> {
+    match iter.into_iter().find(|item| key_fn(item) == key) {
+        Some(item) => Ok(item),
+        None => Err(NotFoundByKey::new(key)),
+    }
+}
+
+/// Searches an iterator of results for the first element that satisfies a given predicate.
+///
+/// Returns [`TryLocateError::Other`] on the first upstream error, before any later element is checked.
+/// Useful for searching through fallible sources like [`std::fs::read_dir`] or paginated API clients.
+///
+/// # Examples
+///
+/// ```
+/// # use not_found_error::{try_locate, NotFoundError, TryLocateError};
+///
+/// let pages: Vec<Result<i32, String>> = vec![Ok(1), Ok(2), Err("timeout".into()), Ok(3)];
+///
+/// assert_eq!(try_locate(pages.clone(), |&n| n == 2), Ok(2));
+/// assert_eq!(try_locate(pages.clone(), |&n| n == 3), Err(TryLocateError::Other("timeout".into())));
+/// assert_eq!(try_locate(vec![Ok::<i32, String>(1)], |&n| n == 3), Err(TryLocateError::NotFound(NotFoundError::new())));
+/// ```
+pub fn try_locate<T, E>(iter: impl IntoIterator<Item = core::result::Result<T, E>>, mut f: impl FnMut(&T) -> bool) -> core::result::Result<T, TryLocateError<T, E>> {
+    for item in iter {
+        let item = item.map_err(TryLocateError::Other)?;
+        if f(&item) {
+            return Ok(item);
+        }
+    }
+    Err(TryLocateError::NotFound(NotFoundError::new()))
+}
+
+/// Collects references to all elements of an iterator that satisfy a given predicate.
+///
+/// Returns an error only if no element matches.
+///
+/// # Examples
+///
+/// ```
+/// # use not_found_error::{locate_all, NotFoundError};
+///
+/// let numbers = [1, 2, 3, 4];
+///
+/// assert_eq!(locate_all(&numbers, |&&n| n % 2 == 0), Ok(vec![&2, &4]));
+/// assert_eq!(locate_all(&numbers, |&&n| n > 4), Err(NotFoundError::new()));
+/// ```
+pub fn locate_all<'a, T>(iter: impl IntoIterator<Item = &'a T>, f: impl FnMut(&&T) -> bool) -> core::result::Result<Vec<&'a T>, NotFoundError<T>> {
+    let items: Vec<&'a T> = iter.into_iter().filter(f).collect();
+    if items.is_empty() {
+        Err(NotFoundError::new())
+    } else {
+        Ok(items)
+    }
+}
+
+/// Searches a double-ended iterator from the back for an element that satisfies a given predicate and returns a reference to it.
+///
+/// Useful for "most recent matching entry" lookups over chronologically ordered data.
+///
+/// # Examples
+///
+/// ```
+/// # use not_found_error::{locate_last, NotFoundError};
+///
+/// let events = [("login", 1), ("logout", 2), ("login", 3)];
+///
+/// assert_eq!(locate_last(&events, |(name, _)| *name == "login"), Ok(&("login", 3)));
+/// assert_eq!(locate_last(&events, |(name, _)| *name == "signup"), Err(NotFoundError::new()));
+/// ```
+pub fn locate_last<'a, T, I>(iter: I, f: impl FnMut(&&T) -> bool) -> core::result::Result<&'a T, NotFoundError<T>>
+where
+    I: IntoIterator<Item = &'a T>,
+    I::IntoIter: DoubleEndedIterator,
+{
+    iter.into_iter().rfind(f).ok_or_not_found()
+}
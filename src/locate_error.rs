@@ -0,0 +1,56 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::{mark_not_found, AnyNotFound, NotFoundError};
+
+/// Represents an error returned by [`locate_unique`](crate::locate_unique): either no element matched, or more than one did.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{LocateError, NotFoundError};
+///
+/// let error = LocateError::<i32>::NotFound(NotFoundError::new());
+/// assert_eq!(error.to_string(), "i32 not found");
+///
+/// let error = LocateError::<i32>::Ambiguous { count: 3 };
+/// assert_eq!(error.to_string(), "i32 is ambiguous: found 3 matches");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub enum LocateError<T> {
+    NotFound(NotFoundError<T>),
+    Ambiguous { count: usize },
+}
+
+impl<T> Display for LocateError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LocateError::NotFound(error) => Display::fmt(error, f),
+            LocateError::Ambiguous {
+                count,
+            } => write!(f, "{} is ambiguous: found {} matches", type_name::<T>(), count),
+        }
+    }
+}
+
+impl<T: Debug> Error for LocateError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LocateError::NotFound(_) => mark_not_found(None),
+            _ => None,
+        }
+    }
+}
+
+impl<T> AnyNotFound for LocateError<T> {
+    fn is_not_found(&self) -> bool {
+        matches!(self, LocateError::NotFound(_))
+    }
+}
+
+impl<T> From<NotFoundError<T>> for LocateError<T> {
+    fn from(error: NotFoundError<T>) -> Self {
+        Self::NotFound(error)
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{locate, locate_all, locate_by_key, locate_index, locate_last, locate_map, locate_mut, NotFoundByKey, NotFoundError};
+
+/// An extension trait that provides the method syntax for the `locate*` functions
+///
+/// Useful when the search sits in the middle of an iterator pipeline.
+pub trait Locate: IntoIterator + Sized {
+    /// The method form of [`locate`](crate::locate).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{Locate, NotFoundError};
+    ///
+    /// # #[derive(PartialEq, Debug)]
+    /// struct User {
+    ///     id: u32,
+    /// }
+    ///
+    /// let users = vec![User { id: 1 }, User { id: 2 }];
+    ///
+    /// assert_eq!(users.iter().locate(|user| user.id == 2), Ok(&users[1]));
+    /// assert_eq!(users.iter().locate(|user| user.id == 3), Err(NotFoundError::new()));
+    /// ```
+    fn locate<'a, T: 'a>(self, f: impl FnMut(&&T) -> bool) -> Result<&'a T, NotFoundError<T>>
+    where
+        Self: IntoIterator<Item = &'a T>,
+    {
+        locate(self, f)
+    }
+
+    /// The method form of [`locate_mut`](crate::locate_mut).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::Locate;
+    ///
+    /// let mut numbers = vec![1, 2, 3];
+    /// *numbers.iter_mut().locate_mut(|n| **n == 2).unwrap() = 20;
+    /// assert_eq!(numbers, vec![1, 20, 3]);
+    /// ```
+    fn locate_mut<'a, T: 'a>(self, f: impl FnMut(&&mut T) -> bool) -> Result<&'a mut T, NotFoundError<T>>
+    where
+        Self: IntoIterator<Item = &'a mut T>,
+    {
+        locate_mut(self, f)
+    }
+
+    /// The method form of [`locate_map`](crate::locate_map).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::Locate;
+    ///
+    /// let port = ["--verbose", "--port=8080"]
+    ///     .iter()
+    ///     .locate_map(|arg| arg.strip_prefix("--port="));
+    /// assert_eq!(port, Ok("8080"));
+    /// ```
+    fn locate_map<U>(self, f: impl FnMut(Self::Item) -> Option<U>) -> Result<U, NotFoundError<U>> {
+        locate_map(self, f)
+    }
+
+    /// The method form of [`locate_index`](crate::locate_index).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::Locate;
+    ///
+    /// let numbers = [1, 2, 3];
+    /// assert_eq!(numbers.iter().locate_index(|&&n| n == 3), Ok((2, &3)));
+    /// ```
+    fn locate_index<'a, T: 'a>(self, f: impl FnMut(&&T) -> bool) -> Result<(usize, &'a T), NotFoundError<T>>
+    where
+        Self: IntoIterator<Item = &'a T>,
+    {
+        locate_index(self, f)
+    }
+
+    /// The method form of [`locate_by_key`](crate::locate_by_key).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{Locate, NotFoundByKey};
+    ///
+    /// let users = [(1, "alice"), (2, "bob")];
+    /// assert_eq!(users.iter().locate_by_key("bob", |(_, name)| *name), Ok(&(2, "bob")));
+    /// assert_eq!(users.iter().locate_by_key(3, |(id, _)| *id), Err(NotFoundByKey::new(3)));
+    /// ```
+    fn locate_by_key<'a, T: 'a, K, Q: PartialEq<K>>(self, key: K, key_fn: impl FnMut(&'a T) -> Q) -> Result<&'a T, NotFoundByKey<T, K>>
+    where
+        Self: IntoIterator<Item = &'a T>,
+    {
+        locate_by_key(self, key, key_fn)
+    }
+
+    /// The method form of [`locate_all`](crate::locate_all).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::Locate;
+    ///
+    /// let numbers = [1, 2, 3, 4];
+    /// assert_eq!(numbers.iter().locate_all(|&&n| n > 2), Ok(vec![&3, &4]));
+    /// ```
+    fn locate_all<'a, T: 'a>(self, f: impl FnMut(&&T) -> bool) -> Result<Vec<&'a T>, NotFoundError<T>>
+    where
+        Self: IntoIterator<Item = &'a T>,
+    {
+        locate_all(self, f)
+    }
+
+    /// The method form of [`locate_last`](crate::locate_last).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::Locate;
+    ///
+    /// let numbers = [1, 2, 3, 4];
+    /// assert_eq!(numbers.iter().locate_last(|&&n| n % 2 == 1), Ok(&3));
+    /// ```
+    fn locate_last<'a, T: 'a>(self, f: impl FnMut(&&T) -> bool) -> Result<&'a T, NotFoundError<T>>
+    where
+        Self: IntoIterator<Item = &'a T>,
+        Self::IntoIter: DoubleEndedIterator,
+    {
+        locate_last(self, f)
+    }
+}
+
+impl<I: IntoIterator> Locate for I {}
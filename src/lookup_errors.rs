@@ -0,0 +1,180 @@
+//! Errors for lookups that failed for reasons other than plain absence.
+//!
+//! Every error in this module has the same zero-sized, type-labeled design as [`NotFoundError`].
+//!
+//! # Examples
+//!
+//! ```
+//! use not_found_error::NotFoundError;
+//! use not_found_error::lookup_errors::{GoneError, LookupError, LookupErrorKind};
+//!
+//! # struct User;
+//! let error: LookupError<User> = GoneError::new().into();
+//! assert_eq!(error.kind(), LookupErrorKind::Gone);
+//!
+//! let error: LookupError<User> = NotFoundError::new().into();
+//! assert_eq!(error.kind(), LookupErrorKind::NotFound);
+//! ```
+
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::{mark_not_found, AnyNotFound, NotFoundError};
+
+macro_rules! define_lookup_error {
+    ($(#[$meta:meta])* $name:ident, $kind:ident, $message:literal) => {
+        $(#[$meta])*
+        #[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+        pub struct $name<T>(pub PhantomData<T>);
+
+        impl<T> $name<T> {
+            /// Creates a new error.
+            pub fn new() -> Self {
+                Self(PhantomData)
+            }
+
+            /// Returns the kind of this error.
+            pub fn kind(&self) -> LookupErrorKind {
+                LookupErrorKind::$kind
+            }
+        }
+
+        impl<T> Default for $name<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T> Display for $name<T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!("{} ", $message), type_name::<T>())
+            }
+        }
+
+        impl<T: Debug> Error for $name<T> {}
+
+        impl<T> From<$name<T>> for LookupErrorKind {
+            fn from(_error: $name<T>) -> Self {
+                LookupErrorKind::$kind
+            }
+        }
+
+        impl<T> From<$name<T>> for LookupError<T> {
+            fn from(error: $name<T>) -> Self {
+                LookupError::$kind(error)
+            }
+        }
+    };
+}
+
+define_lookup_error!(
+    /// Represents an error indicating that more than one value matched the lookup.
+    ///
+    /// ```
+    /// # use not_found_error::lookup_errors::AmbiguousError;
+    /// assert_eq!(AmbiguousError::<i32>::new().to_string(), "i32 is ambiguous");
+    /// ```
+    AmbiguousError,
+    Ambiguous,
+    "is ambiguous"
+);
+
+define_lookup_error!(
+    /// Represents an error indicating that a value existed but has been deleted.
+    ///
+    /// ```
+    /// # use not_found_error::lookup_errors::GoneError;
+    /// assert_eq!(GoneError::<i32>::new().to_string(), "i32 is gone");
+    /// ```
+    GoneError,
+    Gone,
+    "is gone"
+);
+
+define_lookup_error!(
+    /// Represents an error indicating that a value exists but is no longer valid.
+    ///
+    /// ```
+    /// # use not_found_error::lookup_errors::ExpiredError;
+    /// assert_eq!(ExpiredError::<i32>::new().to_string(), "i32 has expired");
+    /// ```
+    ExpiredError,
+    Expired,
+    "has expired"
+);
+
+/// The reason why a lookup failed (without the type label)
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub enum LookupErrorKind {
+    NotFound,
+    Ambiguous,
+    Gone,
+    Expired,
+}
+
+impl<T: ?Sized> From<NotFoundError<T>> for LookupErrorKind {
+    fn from(_error: NotFoundError<T>) -> Self {
+        LookupErrorKind::NotFound
+    }
+}
+
+/// Represents any of the lookup errors for type `T`
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub enum LookupError<T> {
+    NotFound(NotFoundError<T>),
+    Ambiguous(AmbiguousError<T>),
+    Gone(GoneError<T>),
+    Expired(ExpiredError<T>),
+}
+
+impl<T> LookupError<T> {
+    /// Returns the kind of this error.
+    pub fn kind(&self) -> LookupErrorKind {
+        match self {
+            LookupError::NotFound(_) => LookupErrorKind::NotFound,
+            LookupError::Ambiguous(_) => LookupErrorKind::Ambiguous,
+            LookupError::Gone(_) => LookupErrorKind::Gone,
+            LookupError::Expired(_) => LookupErrorKind::Expired,
+        }
+    }
+}
+
+impl<T> Display for LookupError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LookupError::NotFound(error) => Display::fmt(error, f),
+            LookupError::Ambiguous(error) => Display::fmt(error, f),
+            LookupError::Gone(error) => Display::fmt(error, f),
+            LookupError::Expired(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<T: Debug> Error for LookupError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LookupError::NotFound(_) => mark_not_found(None),
+            _ => None,
+        }
+    }
+}
+
+impl<T> AnyNotFound for LookupError<T> {
+    fn is_not_found(&self) -> bool {
+        matches!(self, LookupError::NotFound(_))
+    }
+}
+
+impl<T> From<NotFoundError<T>> for LookupError<T> {
+    fn from(error: NotFoundError<T>) -> Self {
+        LookupError::NotFound(error)
+    }
+}
+
+impl<T> From<LookupError<T>> for LookupErrorKind {
+    fn from(error: LookupError<T>) -> Self {
+        error.kind()
+    }
+}
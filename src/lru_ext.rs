@@ -0,0 +1,77 @@
+use std::borrow::Borrow;
+use std::hash::{BuildHasher, Hash};
+
+use lru::LruCache;
+
+use crate::{Find, FindError, NotFoundByKey};
+
+/// An extension trait for [`LruCache`] that returns keyed errors instead of `None` (feature `lru`)
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use lru::LruCache;
+/// use not_found_error::{LruCacheExt, NotFoundByKey};
+///
+/// let mut cache = LruCache::new(NonZeroUsize::new(2).unwrap());
+/// cache.put("alice".to_string(), 1);
+///
+/// assert_eq!(cache.get_required("alice"), Ok(&1));
+/// *cache.get_mut_required("alice").unwrap() += 1;
+/// assert_eq!(cache.get_required("bob"), Err(NotFoundByKey::new("bob".to_string())));
+/// ```
+pub trait LruCacheExt<K, V> {
+    /// Returns the value for `key` (marking it as the most recently used), or an error that contains an owned copy of the key.
+    fn get_required<Q: ?Sized + Hash + Eq + ToOwned>(&mut self, key: &Q) -> Result<&V, NotFoundByKey<V, Q::Owned>>
+    where
+        K: Borrow<Q>;
+
+    /// Returns a mutable reference to the value for `key` (marking it as the most recently used), or an error that contains an owned copy of the key.
+    fn get_mut_required<Q: ?Sized + Hash + Eq + ToOwned>(&mut self, key: &Q) -> Result<&mut V, NotFoundByKey<V, Q::Owned>>
+    where
+        K: Borrow<Q>;
+
+    /// Returns the value for `key`, looking it up in `source` and inserting it into the cache on a miss.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use lru::LruCache;
+    /// use not_found_error::{FindError, LruCacheExt, NotFoundError};
+    ///
+    /// let users = |id: &u32| Ok::<_, String>((*id == 1).then(|| "alice".to_string()));
+    /// let mut cache = LruCache::new(NonZeroUsize::new(2).unwrap());
+    ///
+    /// assert_eq!(cache.get_or_try_insert_with(1, &users), Ok(&"alice".to_string()));
+    /// assert!(cache.contains(&1));
+    /// assert_eq!(cache.get_or_try_insert_with(2, &users), Err(FindError::NotFound(NotFoundError::new())));
+    /// ```
+    fn get_or_try_insert_with<F: Find<K, Output = V>>(&mut self, key: K, source: &F) -> Result<&V, FindError<V, F::Error>>;
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> LruCacheExt<K, V> for LruCache<K, V, S> {
+    #[inline(always)]
+    fn get_required<Q: ?Sized + Hash + Eq + ToOwned>(&mut self, key: &Q) -> Result<&V, NotFoundByKey<V, Q::Owned>>
+    where
+        K: Borrow<Q>,
+    {
+        self.get(key)
+            .ok_or_else(|| NotFoundByKey::new(key.to_owned()))
+    }
+
+    #[inline(always)]
+    fn get_mut_required<Q: ?Sized + Hash + Eq + ToOwned>(&mut self, key: &Q) -> Result<&mut V, NotFoundByKey<V, Q::Owned>>
+    where
+        K: Borrow<Q>,
+    {
+        self.get_mut(key)
+            .ok_or_else(|| NotFoundByKey::new(key.to_owned()))
+    }
+
+    #[inline(always)]
+    fn get_or_try_insert_with<F: Find<K, Output = V>>(&mut self, key: K, source: &F) -> Result<&V, FindError<V, F::Error>> {
+        self.try_get_or_insert_with_key(key, |key| source.find_required(key))
+    }
+}
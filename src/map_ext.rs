@@ -0,0 +1,344 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+use std::ops::{Bound, RangeBounds};
+
+use crate::{GetManyMutError, MissingKeysError, NotFoundByKey, NotFoundError};
+
+/// An extension trait for maps that returns typed errors instead of `None`
+///
+/// Implemented for [`HashMap`] and [`BTreeMap`].
+pub trait MapExt<K, V> {
+    /// Returns the values for all `keys` (in the same order), or an error listing every missing key.
+    ///
+    /// The keys may be passed either by value or by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use not_found_error::{MapExt, MissingKeysError};
+    ///
+    /// let users = HashMap::from([(1, "alice"), (2, "bob")]);
+    ///
+    /// assert_eq!(users.require_all_keys([2, 1]), Ok(vec![&"bob", &"alice"]));
+    /// assert_eq!(users.require_all_keys(&[1, 3, 4]), Err(MissingKeysError::new(vec![&3, &4])));
+    /// ```
+    fn require_all_keys<Q: Borrow<K>>(&self, keys: impl IntoIterator<Item = Q>) -> Result<Vec<&V>, MissingKeysError<V, Q>>;
+
+    /// Returns the values for an array of keys, or an error listing every missing key.
+    ///
+    /// Unlike [`MapExt::require_all_keys`], the number of keys is known at compile time and the values are returned as an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use not_found_error::{MapExt, MissingKeysError};
+    ///
+    /// let users = HashMap::from([(1, "alice"), (2, "bob")]);
+    ///
+    /// let [bob, alice] = users.get_many_required([2, 1]).unwrap();
+    /// assert_eq!((bob, alice), (&"bob", &"alice"));
+    /// assert_eq!(users.get_many_required([1, 3, 4]), Err(MissingKeysError::new(vec![3, 4])));
+    /// ```
+    fn get_many_required<Q: Borrow<K>, const N: usize>(&self, keys: [Q; N]) -> Result<[&V; N], MissingKeysError<V, Q>>;
+
+    /// Returns the value for `key`, or an error that contains an owned copy of the key.
+    ///
+    /// Like [`HashMap::get`] and [`BTreeMap::get`], the key may be any borrowed form of the key type (e.g. `&str` for `String` keys).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::{BTreeMap, HashMap};
+    /// use not_found_error::{MapExt, NotFoundByKey};
+    ///
+    /// let users = HashMap::from([("alice".to_string(), 1), ("bob".to_string(), 2)]);
+    ///
+    /// assert_eq!(users.get_or_not_found("bob"), Ok(&2));
+    /// assert_eq!(users.get_or_not_found("carol"), Err(NotFoundByKey::new("carol".to_string())));
+    /// assert_eq!(users.get_or_not_found("carol").unwrap_err().to_string(), "i32 not found by key \"carol\"");
+    ///
+    /// let users = BTreeMap::from([(1, "alice"), (2, "bob")]);
+    ///
+    /// assert_eq!(users.get_or_not_found(&2), Ok(&"bob"));
+    /// assert_eq!(users.get_or_not_found(&3), Err(NotFoundByKey::new(3)));
+    /// ```
+    #[inline(always)]
+    fn get_or_not_found<Q: ?Sized + ToOwned>(&self, key: &Q) -> Result<&V, NotFoundByKey<V, Q::Owned>>
+    where
+        Self: BorrowedLookup<Q, Value = V>,
+    {
+        self.get_borrowed(key)
+            .ok_or_else(|| NotFoundByKey::new(key.to_owned()))
+    }
+
+    /// Removes the value for `key` from the map and returns it, or returns an error that contains an owned copy of the key.
+    ///
+    /// Useful for take-ownership workflows like job queues and session stores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::{BTreeMap, HashMap};
+    /// use not_found_error::{MapExt, NotFoundByKey};
+    ///
+    /// let mut sessions = HashMap::from([("token".to_string(), 42)]);
+    ///
+    /// assert_eq!(sessions.remove_required("token"), Ok(42));
+    /// assert_eq!(sessions.remove_required("token"), Err(NotFoundByKey::new("token".to_string())));
+    ///
+    /// let mut jobs = BTreeMap::from([(1, "build"), (2, "deploy")]);
+    ///
+    /// assert_eq!(jobs.remove_required(&1), Ok("build"));
+    /// assert_eq!(jobs.remove_required(&1), Err(NotFoundByKey::new(1)));
+    /// ```
+    #[inline(always)]
+    fn remove_required<Q: ?Sized + ToOwned>(&mut self, key: &Q) -> Result<V, NotFoundByKey<V, Q::Owned>>
+    where
+        Self: BorrowedLookup<Q, Value = V>,
+    {
+        self.remove_borrowed(key)
+            .ok_or_else(|| NotFoundByKey::new(key.to_owned()))
+    }
+}
+
+/// A map that can be looked up by a borrowed form `Q` of its key type
+///
+/// Implemented for [`HashMap`] (if `Q: Hash + Eq`) and [`BTreeMap`] (if `Q: Ord`), so that [`MapExt`] can accept borrowed keys for both of them.
+pub trait BorrowedLookup<Q: ?Sized> {
+    type Value;
+
+    /// Returns the value for `key`, like [`HashMap::get`] and [`BTreeMap::get`].
+    fn get_borrowed(&self, key: &Q) -> Option<&Self::Value>;
+
+    /// Removes the value for `key` from the map and returns it, like [`HashMap::remove`] and [`BTreeMap::remove`].
+    fn remove_borrowed(&mut self, key: &Q) -> Option<Self::Value>;
+}
+
+impl<K: Hash + Eq + Borrow<Q>, V, S: BuildHasher, Q: ?Sized + Hash + Eq> BorrowedLookup<Q> for HashMap<K, V, S> {
+    type Value = V;
+
+    #[inline(always)]
+    fn get_borrowed(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+
+    #[inline(always)]
+    fn remove_borrowed(&mut self, key: &Q) -> Option<V> {
+        self.remove(key)
+    }
+}
+
+impl<K: Ord + Borrow<Q>, V, Q: ?Sized + Ord> BorrowedLookup<Q> for BTreeMap<K, V> {
+    type Value = V;
+
+    #[inline(always)]
+    fn get_borrowed(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+
+    #[inline(always)]
+    fn remove_borrowed(&mut self, key: &Q) -> Option<V> {
+        self.remove(key)
+    }
+}
+
+/// An extension trait for [`HashMap`] that returns typed errors instead of `None`
+pub trait HashMapExt<K, V> {
+    /// Returns mutable references to the values for an array of keys, or an error listing every missing key.
+    ///
+    /// Returns [`GetManyMutError::Overlapping`] if a key is requested more than once, because the same value can't be borrowed mutably twice.
+    /// The values are collected in a single pass over the map, so the lookup is linear in the size of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use not_found_error::{GetManyMutError, HashMapExt, MissingKeysError};
+    ///
+    /// let mut balances = HashMap::from([("alice", 10), ("bob", 5)]);
+    ///
+    /// let [from, to] = balances.get_many_mut_required(["alice", "bob"].each_ref()).unwrap();
+    /// *from -= 3;
+    /// *to += 3;
+    /// assert_eq!(balances, HashMap::from([("alice", 7), ("bob", 8)]));
+    ///
+    /// let error = balances.get_many_mut_required([&"alice", &"carol"]).unwrap_err();
+    /// assert_eq!(error, GetManyMutError::NotFound(MissingKeysError::new(vec![&"carol"])));
+    ///
+    /// let error = balances.get_many_mut_required([&"alice", &"alice"]).unwrap_err();
+    /// assert_eq!(error, GetManyMutError::Overlapping { key: &"alice" });
+    /// ```
+    fn get_many_mut_required<'k, Q: ?Sized + Hash + Eq, const N: usize>(&mut self, keys: [&'k Q; N]) -> Result<[&mut V; N], GetManyMutError<V, &'k Q>>
+    where
+        K: Borrow<Q>;
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> MapExt<K, V> for HashMap<K, V, S> {
+    fn require_all_keys<Q: Borrow<K>>(&self, keys: impl IntoIterator<Item = Q>) -> Result<Vec<&V>, MissingKeysError<V, Q>> {
+        require_all_keys_with(keys, |key| self.get(key))
+    }
+
+    fn get_many_required<Q: Borrow<K>, const N: usize>(&self, keys: [Q; N]) -> Result<[&V; N], MissingKeysError<V, Q>> {
+        get_many_required_with(keys, |key| self.get(key))
+    }
+}
+
+impl<K: Ord, V> MapExt<K, V> for BTreeMap<K, V> {
+    fn require_all_keys<Q: Borrow<K>>(&self, keys: impl IntoIterator<Item = Q>) -> Result<Vec<&V>, MissingKeysError<V, Q>> {
+        require_all_keys_with(keys, |key| self.get(key))
+    }
+
+    fn get_many_required<Q: Borrow<K>, const N: usize>(&self, keys: [Q; N]) -> Result<[&V; N], MissingKeysError<V, Q>> {
+        get_many_required_with(keys, |key| self.get(key))
+    }
+}
+
+/// An extension trait for [`BTreeMap`] that returns typed errors for nearest-neighbor lookups
+///
+/// The nearest-neighbor lookups are useful for time-series and versioned data, where the entry "at or before" a given key is needed.
+pub trait BTreeMapExt<K, V> {
+    /// Returns the entry with the greatest key that is less than or equal to `key`, or an error if there is no such entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use not_found_error::{BTreeMapExt, NotFoundError};
+    ///
+    /// let versions = BTreeMap::from([(10, "v1"), (20, "v2")]);
+    ///
+    /// assert_eq!(versions.floor_required(&15), Ok((&10, &"v1")));
+    /// assert_eq!(versions.floor_required(&20), Ok((&20, &"v2")));
+    /// assert_eq!(versions.floor_required(&5), Err(NotFoundError::new()));
+    /// ```
+    fn floor_required<Q: ?Sized + Ord>(&self, key: &Q) -> Result<(&K, &V), NotFoundError<V>>
+    where
+        K: Borrow<Q>;
+
+    /// Returns the entry with the least key that is greater than or equal to `key`, or an error if there is no such entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use not_found_error::{BTreeMapExt, NotFoundError};
+    ///
+    /// let versions = BTreeMap::from([(10, "v1"), (20, "v2")]);
+    ///
+    /// assert_eq!(versions.ceiling_required(&15), Ok((&20, &"v2")));
+    /// assert_eq!(versions.ceiling_required(&10), Ok((&10, &"v1")));
+    /// assert_eq!(versions.ceiling_required(&25), Err(NotFoundError::new()));
+    /// ```
+    fn ceiling_required<Q: ?Sized + Ord>(&self, key: &Q) -> Result<(&K, &V), NotFoundError<V>>
+    where
+        K: Borrow<Q>;
+
+    /// Returns the entry with the least key in `range`, or an error if the range is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`BTreeMap::range`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use not_found_error::{BTreeMapExt, NotFoundError};
+    ///
+    /// let versions = BTreeMap::from([(10, "v1"), (20, "v2")]);
+    ///
+    /// assert_eq!(versions.first_in_range_required(11..=20), Ok((&20, &"v2")));
+    /// assert_eq!(versions.first_in_range_required(11..20), Err(NotFoundError::new()));
+    /// ```
+    fn first_in_range_required<Q: ?Sized + Ord>(&self, range: impl RangeBounds<Q>) -> Result<(&K, &V), NotFoundError<V>>
+    where
+        K: Borrow<Q>;
+}
+
+impl<K: Ord, V> BTreeMapExt<K, V> for BTreeMap<K, V> {
+    fn floor_required<Q: ?Sized + Ord>(&self, key: &Q) -> Result<(&K, &V), NotFoundError<V>>
+    where
+        K: Borrow<Q>,
+    {
+        self.range((Bound::Unbounded, Bound::Included(key)))
+            .next_back()
+            .ok_or(NotFoundError::new())
+    }
+
+    fn ceiling_required<Q: ?Sized + Ord>(&self, key: &Q) -> Result<(&K, &V), NotFoundError<V>>
+    where
+        K: Borrow<Q>,
+    {
+        self.range((Bound::Included(key), Bound::Unbounded))
+            .next()
+            .ok_or(NotFoundError::new())
+    }
+
+    fn first_in_range_required<Q: ?Sized + Ord>(&self, range: impl RangeBounds<Q>) -> Result<(&K, &V), NotFoundError<V>>
+    where
+        K: Borrow<Q>,
+    {
+        self.range(range).next().ok_or(NotFoundError::new())
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> HashMapExt<K, V> for HashMap<K, V, S> {
+    fn get_many_mut_required<'k, Q: ?Sized + Hash + Eq, const N: usize>(&mut self, keys: [&'k Q; N]) -> Result<[&mut V; N], GetManyMutError<V, &'k Q>>
+    where
+        K: Borrow<Q>,
+    {
+        if let Some(key) = keys
+            .iter()
+            .enumerate()
+            .find_map(|(index, key)| keys[..index].contains(key).then_some(*key))
+        {
+            return Err(GetManyMutError::Overlapping {
+                key,
+            });
+        }
+        let mut values: [Option<&mut V>; N] = std::array::from_fn(|_| None);
+        for (key, value) in self.iter_mut() {
+            if let Some(index) = keys.iter().position(|&k| key.borrow() == k) {
+                values[index] = Some(value);
+            }
+        }
+        collect_values(keys, values).map_err(GetManyMutError::from)
+    }
+}
+
+fn get_many_required_with<'a, K, V, Q: Borrow<K>, const N: usize>(keys: [Q; N], mut get: impl FnMut(&K) -> Option<&'a V>) -> Result<[&'a V; N], MissingKeysError<V, Q>> {
+    let values = keys.each_ref().map(|key| get(key.borrow()));
+    collect_values(keys, values)
+}
+
+/// Returns the values if all of them are present, or an error with the keys of the missing ones.
+fn collect_values<V, Q, T, const N: usize>(keys: [Q; N], values: [Option<T>; N]) -> Result<[T; N], MissingKeysError<V, Q>> {
+    let mut found = Vec::with_capacity(N);
+    let mut missing = Vec::new();
+    for (key, value) in keys.into_iter().zip(values) {
+        match value {
+            Some(value) => found.push(value),
+            None => missing.push(key),
+        }
+    }
+    found.try_into().map_err(|_| MissingKeysError::new(missing))
+}
+
+fn require_all_keys_with<'a, K, V, Q: Borrow<K>>(keys: impl IntoIterator<Item = Q>, mut get: impl FnMut(&K) -> Option<&'a V>) -> Result<Vec<&'a V>, MissingKeysError<V, Q>> {
+    let mut values = Vec::new();
+    let mut missing = Vec::new();
+    for key in keys {
+        match get(key.borrow()) {
+            Some(value) => values.push(value),
+            None => missing.push(key),
+        }
+    }
+    if missing.is_empty() {
+        Ok(values)
+    } else {
+        Err(MissingKeysError::new(missing))
+    }
+}
@@ -0,0 +1,46 @@
+use crate::NotFoundError;
+
+/// An extension trait for `Result<T, NotFoundError<A>>` to relabel the missing type
+///
+/// Useful when forwarding errors up an abstraction layer: the `Ok` arm is left untouched.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{MapNotFound, NotFoundError, Require};
+///
+/// # #[derive(PartialEq, Debug)]
+/// # struct User;
+/// let row: Result<i32, NotFoundError<i32>> = None.require();
+///
+/// let user: Result<i32, NotFoundError<User>> = row.map_not_found();
+/// assert_eq!(user, Err(NotFoundError::new()));
+///
+/// let user = Some(1).require().map_not_found_with(|_| "user not found");
+/// assert_eq!(user, Ok(1));
+/// ```
+pub trait MapNotFound {
+    type T;
+    type A: ?Sized;
+
+    /// Changes the type parameter of the error from `A` to `B`.
+    fn map_not_found<B: ?Sized>(self) -> Result<Self::T, NotFoundError<B>>;
+
+    /// Maps the error with a closure.
+    fn map_not_found_with<F>(self, f: impl FnOnce(NotFoundError<Self::A>) -> F) -> Result<Self::T, F>;
+}
+
+impl<T, A: ?Sized> MapNotFound for Result<T, NotFoundError<A>> {
+    type T = T;
+    type A = A;
+
+    #[inline(always)]
+    fn map_not_found<B: ?Sized>(self) -> Result<T, NotFoundError<B>> {
+        self.map_err(|_| NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn map_not_found_with<F>(self, f: impl FnOnce(NotFoundError<A>) -> F) -> Result<T, F> {
+        self.map_err(f)
+    }
+}
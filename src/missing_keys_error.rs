@@ -0,0 +1,61 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::report::NotFoundReport;
+use crate::{mark_not_found, AnyNotFound, DynNotFoundError};
+
+/// Represents an error indicating that values of type `V` were not found by some of the requested keys of type `K`.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::MissingKeysError;
+///
+/// let error = MissingKeysError::<String, u32>::new(vec![1, 3]);
+/// assert_eq!(error.to_string(), "alloc::string::String not found by keys [1, 3]");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Debug)]
+pub struct MissingKeysError<V, K> {
+    pub keys: Vec<K>,
+    pub phantom: PhantomData<V>,
+}
+
+impl<V, K> MissingKeysError<V, K> {
+    /// Creates a new `MissingKeysError` with the given missing keys.
+    pub fn new(keys: Vec<K>) -> Self {
+        Self {
+            keys,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<V, K: Debug> Display for MissingKeysError<V, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} not found by keys {:?}", type_name::<V>(), self.keys)
+    }
+}
+
+impl<V: Debug, K: Debug> Error for MissingKeysError<V, K> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        mark_not_found(None)
+    }
+}
+
+impl<V, K> AnyNotFound for MissingKeysError<V, K> {
+    fn is_not_found(&self) -> bool {
+        true
+    }
+}
+
+impl<V, K: Debug> From<MissingKeysError<V, K>> for NotFoundReport {
+    fn from(error: MissingKeysError<V, K>) -> Self {
+        error
+            .keys
+            .iter()
+            .map(DynNotFoundError::with_key::<V>)
+            .collect()
+    }
+}
@@ -0,0 +1,90 @@
+use std::future::Future;
+use std::hash::{BuildHasher, Hash};
+
+use moka::Equivalent;
+
+use crate::{Find, FindError, NotFoundByKey};
+
+/// An extension trait for [`moka::sync::Cache`] that returns keyed errors instead of `None` (feature `moka`)
+///
+/// # Examples
+///
+/// ```
+/// use moka::sync::Cache;
+/// use not_found_error::{MokaCacheExt, NotFoundByKey};
+///
+/// let cache = Cache::new(100);
+/// cache.insert("alice".to_string(), 1);
+///
+/// assert_eq!(cache.get_required("alice"), Ok(1));
+/// assert_eq!(cache.get_required("bob"), Err(NotFoundByKey::new("bob".to_string())));
+/// ```
+pub trait MokaCacheExt<K, V> {
+    /// Returns a clone of the value for `key`, or an error that contains an owned copy of the key.
+    fn get_required<Q: ?Sized + Hash + Equivalent<K> + ToOwned>(&self, key: &Q) -> Result<V, NotFoundByKey<V, Q::Owned>>;
+
+    /// Returns a clone of the value for `key`, looking it up in `source` and inserting it into the cache on a miss.
+    ///
+    /// Concurrent misses for the same key are not coalesced: each of them queries the source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use moka::sync::Cache;
+    /// use not_found_error::{FindError, MokaCacheExt, NotFoundError};
+    ///
+    /// let users = |id: &u32| Ok::<_, String>((*id == 1).then(|| "alice".to_string()));
+    /// let cache = Cache::new(100);
+    ///
+    /// assert_eq!(cache.get_or_try_insert_with(1, &users), Ok("alice".to_string()));
+    /// assert!(cache.contains_key(&1));
+    /// assert_eq!(cache.get_or_try_insert_with(2, &users), Err(FindError::NotFound(NotFoundError::new())));
+    /// ```
+    fn get_or_try_insert_with<F: Find<K, Output = V>>(&self, key: K, source: &F) -> Result<V, FindError<V, F::Error>>;
+}
+
+impl<K: Hash + Eq + Send + Sync + 'static, V: Clone + Send + Sync + 'static, S: BuildHasher + Clone + Send + Sync + 'static> MokaCacheExt<K, V> for moka::sync::Cache<K, V, S> {
+    #[inline(always)]
+    fn get_required<Q: ?Sized + Hash + Equivalent<K> + ToOwned>(&self, key: &Q) -> Result<V, NotFoundByKey<V, Q::Owned>> {
+        self.get(key)
+            .ok_or_else(|| NotFoundByKey::new(key.to_owned()))
+    }
+
+    fn get_or_try_insert_with<F: Find<K, Output = V>>(&self, key: K, source: &F) -> Result<V, FindError<V, F::Error>> {
+        if let Some(value) = self.get(&key) {
+            return Ok(value);
+        }
+        let value = source.find_required(&key)?;
+        self.insert(key, value.clone());
+        Ok(value)
+    }
+}
+
+/// An extension trait for [`moka::future::Cache`] that returns keyed errors instead of `None` (feature `moka`)
+///
+/// # Examples
+///
+/// ```
+/// use moka::future::Cache;
+/// use not_found_error::{MokaFutureCacheExt, NotFoundByKey};
+///
+/// futures::executor::block_on(async {
+///     let cache = Cache::new(100);
+///     cache.insert("alice".to_string(), 1).await;
+///
+///     assert_eq!(cache.get_required("alice").await, Ok(1));
+///     assert_eq!(cache.get_required("bob").await, Err(NotFoundByKey::new("bob".to_string())));
+/// });
+/// ```
+pub trait MokaFutureCacheExt<K, V> {
+    /// Returns a clone of the value for `key`, or an error that contains an owned copy of the key.
+    fn get_required<'a, Q: ?Sized + Hash + Equivalent<K> + ToOwned + Sync>(&'a self, key: &'a Q) -> impl Future<Output = Result<V, NotFoundByKey<V, Q::Owned>>> + Send + 'a;
+}
+
+impl<K: Hash + Eq + Send + Sync + 'static, V: Clone + Send + Sync + 'static, S: BuildHasher + Clone + Send + Sync + 'static> MokaFutureCacheExt<K, V> for moka::future::Cache<K, V, S> {
+    async fn get_required<'a, Q: ?Sized + Hash + Equivalent<K> + ToOwned + Sync>(&'a self, key: &'a Q) -> Result<V, NotFoundByKey<V, Q::Owned>> {
+        self.get(key)
+            .await
+            .ok_or_else(|| NotFoundByKey::new(key.to_owned()))
+    }
+}
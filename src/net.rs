@@ -0,0 +1,133 @@
+//! Helpers for resolving socket addresses with typed "not found" errors.
+//!
+//! An empty resolution set is reported as [`FindByKeyError::NotFound`], distinct from the IO errors of the resolver.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::net::SocketAddr;
+//! use not_found_error::net;
+//!
+//! let addr = net::resolve_required("127.0.0.1:8080").unwrap();
+//! assert_eq!(addr, SocketAddr::from(([127, 0, 0, 1], 8080)));
+//! ```
+
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs};
+
+#[cfg(feature = "if-addrs")]
+use if_addrs::Interface;
+
+use crate::{FindByKeyError, NotFoundByKey};
+#[cfg(feature = "if-addrs")]
+use crate::{FindError, NotFoundError};
+
+/// A type alias for the error returned by the `resolve_*` functions: either an IO error of the resolver or a `NotFoundByKey` that records the host
+pub type ResolveError = FindByKeyError<SocketAddr, String, io::Error>;
+
+/// A type alias for the error returned by [`find_interface_required`]: either an IO error or a `NotFoundByKey` that records the interface name
+#[cfg(feature = "if-addrs")]
+pub type InterfaceError = FindByKeyError<Interface, String, io::Error>;
+
+/// A type alias for the error returned by [`find_interface_required_by`]: either an IO error or `NotFoundError<Interface>`
+#[cfg(feature = "if-addrs")]
+pub type FindInterfaceError = FindError<Interface, io::Error>;
+
+/// Returns the first address that `host` resolves to.
+///
+/// The `host` must include a port (e.g. `"example.com:443"`), as required by [`ToSocketAddrs`].
+///
+/// # Examples
+///
+/// ```
+/// use std::net::SocketAddr;
+/// use not_found_error::net::{self, ResolveError};
+///
+/// assert_eq!(net::resolve_required("[::1]:443").unwrap(), SocketAddr::from(([0, 0, 0, 0, 0, 0, 0, 1], 443)));
+/// assert!(matches!(net::resolve_required("missing-port"), Err(ResolveError::Other(_))));
+/// ```
+pub fn resolve_required(host: &str) -> Result<SocketAddr, ResolveError> {
+    resolve_first(host, |_| true)
+}
+
+/// Returns the first IPv4 address that `host` resolves to.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::SocketAddr;
+/// use not_found_error::net::{self, ResolveError};
+/// use not_found_error::NotFoundByKey;
+///
+/// assert_eq!(net::resolve_v4_required("127.0.0.1:80").unwrap(), SocketAddr::from(([127, 0, 0, 1], 80)));
+///
+/// let error = net::resolve_v4_required("[::1]:80").unwrap_err();
+/// assert!(matches!(error, ResolveError::NotFound(error) if error == NotFoundByKey::new("[::1]:80".to_string())));
+/// ```
+pub fn resolve_v4_required(host: &str) -> Result<SocketAddr, ResolveError> {
+    resolve_first(host, SocketAddr::is_ipv4)
+}
+
+/// Returns the first IPv6 address that `host` resolves to.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::net::{self, ResolveError};
+///
+/// assert!(net::resolve_v6_required("[::1]:80").unwrap().is_ipv6());
+/// assert!(matches!(net::resolve_v6_required("127.0.0.1:80"), Err(ResolveError::NotFound(_))));
+/// ```
+pub fn resolve_v6_required(host: &str) -> Result<SocketAddr, ResolveError> {
+    resolve_first(host, SocketAddr::is_ipv6)
+}
+
+fn resolve_first(host: &str, f: impl FnMut(&SocketAddr) -> bool) -> Result<SocketAddr, ResolveError> {
+    host.to_socket_addrs()
+        .map_err(FindByKeyError::Other)?
+        .find(f)
+        .ok_or_else(|| FindByKeyError::NotFound(NotFoundByKey::new(host.to_string())))
+}
+
+/// Returns the first address of the network interface named `name` (e.g. `"eth0"`).
+///
+/// Requires the `if-addrs` feature.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::net::{self, InterfaceError};
+///
+/// let error = net::find_interface_required("no-such-nic0").unwrap_err();
+/// assert!(matches!(error, InterfaceError::NotFound(_)));
+/// assert!(error.to_string().ends_with("Interface not found by key \"no-such-nic0\""));
+/// ```
+#[cfg(feature = "if-addrs")]
+pub fn find_interface_required(name: &str) -> Result<Interface, InterfaceError> {
+    if_addrs::get_if_addrs()
+        .map_err(FindByKeyError::Other)?
+        .into_iter()
+        .find(|interface| interface.name == name)
+        .ok_or_else(|| FindByKeyError::NotFound(NotFoundByKey::new(name.to_string())))
+}
+
+/// Returns the first network interface that satisfies the predicate.
+///
+/// Requires the `if-addrs` feature.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::net::{self, FindInterfaceError};
+///
+/// let error = net::find_interface_required_by(|interface| interface.name == "no-such-nic0").unwrap_err();
+/// assert!(matches!(error, FindInterfaceError::NotFound(_)));
+/// ```
+#[cfg(feature = "if-addrs")]
+pub fn find_interface_required_by(f: impl FnMut(&Interface) -> bool) -> Result<Interface, FindInterfaceError> {
+    let interfaces = if_addrs::get_if_addrs().map_err(FindError::Other)?;
+    interfaces
+        .into_iter()
+        .find(f)
+        .ok_or(FindError::NotFound(NotFoundError::new()))
+}
@@ -0,0 +1,60 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::{mark_not_found, AnyNotFound, NotFoundError};
+
+/// Represents an error indicating that fewer values of type `T` were available than required.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::NotEnoughItemsError;
+///
+/// let error = NotEnoughItemsError::<u32>::new(3, 2);
+/// assert_eq!(error.to_string(), "not enough u32: expected at least 3, found 2");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct NotEnoughItemsError<T> {
+    /// The minimum number of items that was required
+    pub expected: usize,
+    /// The number of items that was available
+    pub actual: usize,
+    pub phantom: PhantomData<T>,
+}
+
+impl<T> NotEnoughItemsError<T> {
+    /// Creates a new `NotEnoughItemsError` with the expected and actual counts.
+    pub fn new(expected: usize, actual: usize) -> Self {
+        Self {
+            expected,
+            actual,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Display for NotEnoughItemsError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not enough {}: expected at least {}, found {}", type_name::<T>(), self.expected, self.actual)
+    }
+}
+
+impl<T: Debug> Error for NotEnoughItemsError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        mark_not_found(None)
+    }
+}
+
+impl<T> AnyNotFound for NotEnoughItemsError<T> {
+    fn is_not_found(&self) -> bool {
+        true
+    }
+}
+
+impl<T> From<NotEnoughItemsError<T>> for NotFoundError<T> {
+    fn from(_error: NotEnoughItemsError<T>) -> Self {
+        NotFoundError::new()
+    }
+}
@@ -0,0 +1,99 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::{mark_not_found, AnyNotFound, DynNotFoundError, NotFoundError};
+
+/// Represents an error indicating that a value of type `T` was not found by a key of type `K`.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::NotFoundByKey;
+///
+/// let error = NotFoundByKey::<String, u32>::new(42);
+/// assert_eq!(error.to_string(), "alloc::string::String not found by key 42");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct NotFoundByKey<T, K> {
+    pub key: K,
+    pub phantom: PhantomData<T>,
+}
+
+impl<T, K> NotFoundByKey<T, K> {
+    /// Creates a new `NotFoundByKey` with the given key.
+    pub fn new(key: K) -> Self {
+        Self {
+            key,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the key.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+}
+
+impl<T, K: Debug> Display for NotFoundByKey<T, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} not found by key {:?}", type_name::<T>(), self.key)
+    }
+}
+
+impl<T: Debug, K: Debug> Error for NotFoundByKey<T, K> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        mark_not_found(None)
+    }
+}
+
+impl<T, K> From<NotFoundByKey<T, K>> for NotFoundError<T> {
+    fn from(_error: NotFoundByKey<T, K>) -> Self {
+        NotFoundError::new()
+    }
+}
+
+impl<T, K: Debug> From<NotFoundByKey<T, K>> for DynNotFoundError {
+    fn from(error: NotFoundByKey<T, K>) -> Self {
+        DynNotFoundError::with_key::<T>(&error.key)
+    }
+}
+
+impl<T, K> AnyNotFound for NotFoundByKey<T, K> {
+    fn is_not_found(&self) -> bool {
+        true
+    }
+}
+
+/// An extension trait for `Option<T>` to convert it to `Result<T, NotFoundByKey<B, K>>`
+///
+/// This is a keyed version of [`OkOrNotFound`](crate::OkOrNotFound).
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use not_found_error::{NotFoundByKey, OkOrNotFoundBy};
+///
+/// # #[derive(PartialEq, Debug)]
+/// # struct User;
+/// let users = HashMap::from([(1, "alice")]);
+///
+/// let result = users.get(&2).ok_or_not_found_by::<User, _>(2);
+/// assert_eq!(result, Err(NotFoundByKey::new(2)));
+/// ```
+pub trait OkOrNotFoundBy {
+    type T;
+
+    fn ok_or_not_found_by<B, K>(self, key: K) -> Result<Self::T, NotFoundByKey<B, K>>;
+}
+
+impl<T> OkOrNotFoundBy for Option<T> {
+    type T = T;
+
+    #[inline(always)]
+    fn ok_or_not_found_by<B, K>(self, key: K) -> Result<T, NotFoundByKey<B, K>> {
+        self.ok_or(NotFoundByKey::new(key))
+    }
+}
@@ -0,0 +1,83 @@
+use once_cell::{sync, unsync};
+
+use crate::{NotFoundError, OnceExt};
+
+/// Requires the `once_cell` feature.
+///
+/// ```
+/// use once_cell::sync::Lazy;
+/// use not_found_error::{NotFoundError, OnceExt};
+///
+/// let lazy = Lazy::new(|| 42);
+/// assert_eq!(lazy.get_required(), Err(NotFoundError::new()));
+/// assert_eq!(*lazy, 42);
+/// assert_eq!(lazy.get_required(), Ok(&42));
+/// ```
+impl<T, F: FnOnce() -> T> OnceExt for sync::Lazy<T, F> {
+    type T = T;
+
+    #[inline(always)]
+    fn get_required(&self) -> Result<&T, NotFoundError<T>> {
+        sync::Lazy::get(self).ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self) -> Result<&mut T, NotFoundError<T>> {
+        sync::Lazy::get_mut(self).ok_or(NotFoundError::new())
+    }
+}
+
+/// Requires the `once_cell` feature.
+impl<T, F: FnOnce() -> T> OnceExt for unsync::Lazy<T, F> {
+    type T = T;
+
+    #[inline(always)]
+    fn get_required(&self) -> Result<&T, NotFoundError<T>> {
+        unsync::Lazy::get(self).ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self) -> Result<&mut T, NotFoundError<T>> {
+        unsync::Lazy::get_mut(self).ok_or(NotFoundError::new())
+    }
+}
+
+/// Requires the `once_cell` feature.
+///
+/// ```
+/// use once_cell::sync::OnceCell;
+/// use not_found_error::{NotFoundError, OnceExt};
+///
+/// let cell = OnceCell::new();
+/// assert_eq!(cell.get_required(), Err(NotFoundError::<u32>::new()));
+/// cell.set(1).unwrap();
+/// assert_eq!(cell.get_required(), Ok(&1));
+/// ```
+impl<T> OnceExt for sync::OnceCell<T> {
+    type T = T;
+
+    #[inline(always)]
+    fn get_required(&self) -> Result<&T, NotFoundError<T>> {
+        self.get().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self) -> Result<&mut T, NotFoundError<T>> {
+        self.get_mut().ok_or(NotFoundError::new())
+    }
+}
+
+/// Requires the `once_cell` feature.
+impl<T> OnceExt for unsync::OnceCell<T> {
+    type T = T;
+
+    #[inline(always)]
+    fn get_required(&self) -> Result<&T, NotFoundError<T>> {
+        self.get().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self) -> Result<&mut T, NotFoundError<T>> {
+        self.get_mut().ok_or(NotFoundError::new())
+    }
+}
@@ -0,0 +1,109 @@
+use std::cell::{LazyCell, OnceCell};
+use std::sync::{LazyLock, OnceLock};
+
+use crate::{NotFoundError, Require};
+
+/// An extension trait for lazily initialized cells that returns `NotFoundError<T>` when the cell is uninitialized
+///
+/// Implemented for [`OnceLock`] and [`OnceCell`] (and for the cells of the `once_cell` crate behind the `once_cell` feature).
+/// [`LazyLock`] and [`LazyCell`] are not supported: checking them for initialization without forcing them needs `LazyLock::get` and `LazyCell::get`, which are only stable in recent Rust versions.
+/// Use a `OnceLock` (or the `Lazy` types of the `once_cell` crate behind the `once_cell` feature) if the initialization must be checked.
+/// A `LazyLock<Option<T>>` (or `LazyCell<Option<T>>`) whose initializer may find nothing implements [`Require`] instead (forcing the initializer).
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::OnceLock;
+/// use not_found_error::{NotFoundError, OnceExt};
+///
+/// let mut config = OnceLock::new();
+/// assert_eq!(config.get_required(), Err(NotFoundError::<String>::new()));
+///
+/// config.set("production".to_string()).unwrap();
+/// assert_eq!(config.get_required().unwrap(), "production");
+///
+/// config.get_mut_required().unwrap().push_str("-eu");
+/// assert_eq!(config.get_required().unwrap(), "production-eu");
+/// ```
+pub trait OnceExt {
+    type T;
+
+    /// Returns a reference to the value, or an error if the cell is uninitialized.
+    fn get_required(&self) -> Result<&Self::T, NotFoundError<Self::T>>;
+
+    /// Returns a mutable reference to the value, or an error if the cell is uninitialized.
+    fn get_mut_required(&mut self) -> Result<&mut Self::T, NotFoundError<Self::T>>;
+}
+
+impl<T> OnceExt for OnceLock<T> {
+    type T = T;
+
+    #[inline(always)]
+    fn get_required(&self) -> Result<&T, NotFoundError<T>> {
+        self.get().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self) -> Result<&mut T, NotFoundError<T>> {
+        self.get_mut().ok_or(NotFoundError::new())
+    }
+}
+
+impl<T> OnceExt for OnceCell<T> {
+    type T = T;
+
+    #[inline(always)]
+    fn get_required(&self) -> Result<&T, NotFoundError<T>> {
+        self.get().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self) -> Result<&mut T, NotFoundError<T>> {
+        self.get_mut().ok_or(NotFoundError::new())
+    }
+}
+
+/// Forces the initializer and requires its value.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::LazyLock;
+/// use not_found_error::{NotFoundError, Require};
+///
+/// static HOME: LazyLock<Option<String>> = LazyLock::new(|| Some("/home/alice".to_string()));
+/// static PROXY: LazyLock<Option<String>> = LazyLock::new(|| None);
+///
+/// assert_eq!(HOME.require().map(String::as_str), Ok("/home/alice"));
+/// assert_eq!(PROXY.require(), Err(NotFoundError::<String>::new()));
+/// ```
+impl<'a, T, F: FnOnce() -> Option<T>> Require for &'a LazyLock<Option<T>, F> {
+    type T = &'a T;
+    type Label = T;
+
+    #[inline(always)]
+    fn require(self) -> Result<&'a T, NotFoundError<T>> {
+        LazyLock::force(self).as_ref().ok_or(NotFoundError::new())
+    }
+}
+
+/// Forces the initializer and requires its value.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::LazyCell;
+/// use not_found_error::{NotFoundError, Require};
+///
+/// let proxy = LazyCell::new(|| std::env::var("NOT_FOUND_ERROR_PROXY").ok());
+/// assert_eq!(proxy.require(), Err(NotFoundError::<String>::new()));
+/// ```
+impl<'a, T, F: FnOnce() -> Option<T>> Require for &'a LazyCell<Option<T>, F> {
+    type T = &'a T;
+    type Label = T;
+
+    #[inline(always)]
+    fn require(self) -> Result<&'a T, NotFoundError<T>> {
+        LazyCell::force(self).as_ref().ok_or(NotFoundError::new())
+    }
+}
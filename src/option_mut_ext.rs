@@ -0,0 +1,31 @@
+use crate::NotFoundError;
+
+/// An extension trait for `&mut Option<T>`
+pub trait OptionMutExt {
+    type T;
+
+    /// Takes the value out of the option (leaving `None` in its place) or returns `NotFoundError<T>`.
+    ///
+    /// Useful for state machines and builders that consume optional fields exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundError, OptionMutExt};
+    ///
+    /// let mut token = Some(42);
+    /// assert_eq!(token.take_required(), Ok(42));
+    /// assert_eq!(token.take_required(), Err(NotFoundError::new()));
+    /// assert_eq!(token, None);
+    /// ```
+    fn take_required(&mut self) -> Result<Self::T, NotFoundError<Self::T>>;
+}
+
+impl<T> OptionMutExt for Option<T> {
+    type T = T;
+
+    #[inline(always)]
+    fn take_required(&mut self) -> Result<T, NotFoundError<T>> {
+        self.take().ok_or(NotFoundError::new())
+    }
+}
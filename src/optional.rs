@@ -0,0 +1,69 @@
+use crate::{AnyNotFound, NotFoundError};
+
+/// An extension trait for `Result<T, NotFoundError<M>>` to convert it back to `Option<T>`
+///
+/// Useful for callers that treat "not found" as a normal case (similar to `.optional()` in diesel).
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{Optional, Require};
+///
+/// assert_eq!(Some(1).require().optional(), Some(1));
+/// assert_eq!(None::<i32>.require().optional(), None);
+/// ```
+///
+/// # See also
+///
+/// - [`TryOptional`]: Trait for converting `Result<T, E>` to `Result<Option<T>, E>` where `E` may contain a "not found" case
+pub trait Optional {
+    type T;
+
+    fn optional(self) -> Option<Self::T>;
+}
+
+impl<T, M: ?Sized> Optional for Result<T, NotFoundError<M>> {
+    type T = T;
+
+    #[inline(always)]
+    fn optional(self) -> Option<T> {
+        self.ok()
+    }
+}
+
+/// An extension trait for `Result<T, E>` to convert "not found" errors to `Ok(None)` while keeping other errors
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{LocateError, NotFoundError, TryOptional};
+///
+/// let result: Result<i32, LocateError<i32>> = Err(LocateError::NotFound(NotFoundError::new()));
+/// assert_eq!(result.try_optional(), Ok(None));
+///
+/// let result: Result<i32, LocateError<i32>> = Err(LocateError::Ambiguous { count: 2 });
+/// assert_eq!(result.try_optional(), Err(LocateError::Ambiguous { count: 2 }));
+///
+/// let result: Result<i32, LocateError<i32>> = Ok(1);
+/// assert_eq!(result.try_optional(), Ok(Some(1)));
+/// ```
+pub trait TryOptional {
+    type T;
+    type E;
+
+    fn try_optional(self) -> Result<Option<Self::T>, Self::E>;
+}
+
+impl<T, E: AnyNotFound> TryOptional for Result<T, E> {
+    type T = T;
+    type E = E;
+
+    #[inline(always)]
+    fn try_optional(self) -> Result<Option<T>, E> {
+        match self {
+            Ok(value) => Ok(Some(value)),
+            Err(error) if error.is_not_found() => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::{mark_not_found, AnyNotFound, NotFoundError};
+
+/// Represents an error indicating that a value was not found because of another error.
+///
+/// Created by [`NotFoundError::with_source`] or [`OrNotFound::or_not_found_with_source`].
+///
+/// # Examples
+///
+/// ```
+/// use std::error::Error;
+/// use std::io;
+/// use not_found_error::NotFoundError;
+///
+/// # #[derive(PartialEq, Debug)]
+/// # struct Config;
+/// let error = NotFoundError::<Config>::new().with_source(io::Error::from(io::ErrorKind::PermissionDenied));
+/// assert!(error.to_string().ends_with("Config not found"));
+/// assert!(error.source().is_some());
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct NotFoundWithSource<T, E> {
+    pub error: NotFoundError<T>,
+    pub source: E,
+}
+
+impl<T> NotFoundError<T> {
+    /// Attaches the error that caused this error.
+    pub fn with_source<E>(self, source: E) -> NotFoundWithSource<T, E> {
+        NotFoundWithSource {
+            error: self,
+            source,
+        }
+    }
+}
+
+impl<T, E> Display for NotFoundWithSource<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<T: Debug, E: Error + 'static> Error for NotFoundWithSource<T, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        mark_not_found(Some(&self.source))
+    }
+}
+
+impl<T, E> From<NotFoundWithSource<T, E>> for NotFoundError<T> {
+    fn from(error: NotFoundWithSource<T, E>) -> Self {
+        error.error
+    }
+}
+
+impl<T, E> AnyNotFound for NotFoundWithSource<T, E> {
+    fn is_not_found(&self) -> bool {
+        true
+    }
+}
+
+/// An extension trait for `Result<T, E>` to replace any error with `NotFoundError<M>`
+///
+/// Useful for collapsing "lookup failed for whatever reason" into a single typed error.
+///
+/// # Examples
+///
+/// ```
+/// use std::error::Error;
+/// use not_found_error::{NotFoundError, OrNotFound};
+///
+/// # #[derive(PartialEq, Debug)]
+/// # struct Port;
+/// let port: Result<u16, NotFoundError<Port>> = "80".parse::<u16>().or_not_found();
+/// assert_eq!(port, Ok(80));
+///
+/// let port: Result<u16, NotFoundError<Port>> = "http".parse::<u16>().or_not_found();
+/// assert!(port.is_err());
+///
+/// // Keep the original error as the source
+/// let error = "http".parse::<u16>().or_not_found_with_source::<Port>().unwrap_err();
+/// assert_eq!(error.source().unwrap().to_string(), "invalid digit found in string");
+/// ```
+pub trait OrNotFound {
+    type T;
+    type E;
+
+    fn or_not_found<M: ?Sized>(self) -> Result<Self::T, NotFoundError<M>>;
+
+    fn or_not_found_with_source<M>(self) -> Result<Self::T, NotFoundWithSource<M, Self::E>>;
+}
+
+impl<T, E> OrNotFound for Result<T, E> {
+    type T = T;
+    type E = E;
+
+    #[inline(always)]
+    fn or_not_found<M: ?Sized>(self) -> Result<T, NotFoundError<M>> {
+        self.map_err(|_| NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn or_not_found_with_source<M>(self) -> Result<T, NotFoundWithSource<M, E>> {
+        self.map_err(|error| NotFoundError::new().with_source(error))
+    }
+}
@@ -0,0 +1,72 @@
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{LocateError, NotFoundError};
+
+/// Searches a parallel iterator for an element that satisfies a given predicate and returns a reference to it.
+///
+/// Uses [`ParallelIterator::find_any`], so the returned element is not necessarily the first match in sequential order.
+/// Use [`par_locate_first`] if the order matters.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{par_locate, NotFoundError};
+///
+/// let numbers: Vec<u32> = (0..10_000).collect();
+///
+/// assert_eq!(par_locate(&numbers, |&&n| n == 4_242), Ok(&4_242));
+/// assert_eq!(par_locate(&numbers, |&&n| n == 10_000), Err(NotFoundError::new()));
+/// ```
+pub fn par_locate<'a, T: Sync + 'a>(iter: impl IntoParallelIterator<Item = &'a T>, f: impl Fn(&&'a T) -> bool + Sync + Send) -> Result<&'a T, NotFoundError<T>> {
+    iter.into_par_iter().find_any(f).ok_or(NotFoundError::new())
+}
+
+/// Searches a parallel iterator for the first element (in sequential order) that satisfies a given predicate and returns a reference to it.
+///
+/// Uses [`ParallelIterator::find_first`].
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{par_locate_first, NotFoundError};
+///
+/// let numbers: Vec<u32> = (0..10_000).collect();
+///
+/// assert_eq!(par_locate_first(&numbers, |&&n| n % 1_000 == 999), Ok(&999));
+/// assert_eq!(par_locate_first(&numbers, |&&n| n == 10_000), Err(NotFoundError::new()));
+/// ```
+pub fn par_locate_first<'a, T: Sync + 'a>(iter: impl IntoParallelIterator<Item = &'a T>, f: impl Fn(&&'a T) -> bool + Sync + Send) -> Result<&'a T, NotFoundError<T>> {
+    iter.into_par_iter()
+        .find_first(f)
+        .ok_or(NotFoundError::new())
+}
+
+/// Searches a parallel iterator for the only element that satisfies a given predicate and returns a reference to it.
+///
+/// This is the parallel counterpart of [`locate_unique`](crate::locate_unique): it consumes the whole iterator and returns [`LocateError::Ambiguous`] if more than one element matches.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{par_locate_unique, LocateError, NotFoundError};
+///
+/// let numbers: Vec<u32> = (0..10_000).collect();
+///
+/// assert_eq!(par_locate_unique(&numbers, |&&n| n == 4_242), Ok(&4_242));
+/// assert_eq!(par_locate_unique(&numbers, |&&n| n % 5_000 == 0), Err(LocateError::Ambiguous { count: 2 }));
+/// assert_eq!(par_locate_unique(&numbers, |&&n| n == 10_000), Err(LocateError::NotFound(NotFoundError::new())));
+/// ```
+pub fn par_locate_unique<'a, T: Sync + 'a>(iter: impl IntoParallelIterator<Item = &'a T>, f: impl Fn(&&'a T) -> bool + Sync + Send) -> Result<&'a T, LocateError<T>> {
+    let matches = iter
+        .into_par_iter()
+        .filter(f)
+        .map(|item| (item, 1))
+        .reduce_with(|(item, left), (_, right)| (item, left + right));
+    match matches {
+        None => Err(LocateError::NotFound(NotFoundError::new())),
+        Some((item, 1)) => Ok(item),
+        Some((_, count)) => Err(LocateError::Ambiguous {
+            count,
+        }),
+    }
+}
@@ -0,0 +1,63 @@
+use std::path::{Path, PathBuf};
+
+use crate::NotFoundByKey;
+
+/// An extension trait for [`Path`] that checks its existence and returns a keyed error with the offending path
+///
+/// The marker type `M` labels the error.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::{Path, PathBuf};
+/// use not_found_error::{NotFoundByKey, PathExt};
+///
+/// #[derive(PartialEq, Debug)]
+/// struct Config;
+///
+/// let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+/// let manifest = manifest_dir.join("Cargo.toml");
+///
+/// assert_eq!(manifest.require_exists::<Config>(), Ok(manifest.as_path()));
+/// assert_eq!(manifest.require_file::<Config>(), Ok(manifest.as_path()));
+/// assert_eq!(manifest.require_dir::<Config>(), Err(NotFoundByKey::new(manifest.clone())));
+/// assert_eq!(manifest_dir.require_dir::<Config>(), Ok(manifest_dir));
+///
+/// let missing = manifest_dir.join("missing.toml");
+/// assert!(missing.require_exists::<Config>().unwrap_err().to_string().ends_with("missing.toml\""));
+/// ```
+pub trait PathExt {
+    /// Returns the path if it exists, or an error that contains the path.
+    fn require_exists<M>(&self) -> Result<&Path, NotFoundByKey<M, PathBuf>>;
+
+    /// Returns the path if it points to a regular file, or an error that contains the path.
+    fn require_file<M>(&self) -> Result<&Path, NotFoundByKey<M, PathBuf>>;
+
+    /// Returns the path if it points to a directory, or an error that contains the path.
+    fn require_dir<M>(&self) -> Result<&Path, NotFoundByKey<M, PathBuf>>;
+}
+
+impl PathExt for Path {
+    #[inline(always)]
+    fn require_exists<M>(&self) -> Result<&Path, NotFoundByKey<M, PathBuf>> {
+        require_path_with(self, Path::exists)
+    }
+
+    #[inline(always)]
+    fn require_file<M>(&self) -> Result<&Path, NotFoundByKey<M, PathBuf>> {
+        require_path_with(self, Path::is_file)
+    }
+
+    #[inline(always)]
+    fn require_dir<M>(&self) -> Result<&Path, NotFoundByKey<M, PathBuf>> {
+        require_path_with(self, Path::is_dir)
+    }
+}
+
+fn require_path_with<M>(path: &Path, check: impl FnOnce(&Path) -> bool) -> Result<&Path, NotFoundByKey<M, PathBuf>> {
+    if check(path) {
+        Ok(path)
+    } else {
+        Err(NotFoundByKey::new(path.to_path_buf()))
+    }
+}
@@ -0,0 +1,34 @@
+use std::iter::Peekable;
+
+use crate::NotFoundError;
+
+/// An extension trait for [`Peekable`] iterators
+pub trait PeekableExt {
+    type T;
+
+    /// Returns a reference to the next element without advancing the iterator or an error if the iterator is exhausted.
+    ///
+    /// Useful for parsers that need to turn "unexpected end of input" into a typed error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundError, PeekableExt};
+    ///
+    /// let mut tokens = ["let", "x"].into_iter().peekable();
+    /// assert_eq!(tokens.require_peek(), Ok(&"let"));
+    /// assert_eq!(tokens.next(), Some("let"));
+    /// assert_eq!(tokens.next(), Some("x"));
+    /// assert_eq!(tokens.require_peek(), Err(NotFoundError::new()));
+    /// ```
+    fn require_peek(&mut self) -> Result<&Self::T, NotFoundError<Self::T>>;
+}
+
+impl<I: Iterator> PeekableExt for Peekable<I> {
+    type T = I::Item;
+
+    #[inline(always)]
+    fn require_peek(&mut self) -> Result<&I::Item, NotFoundError<I::Item>> {
+        self.peek().ok_or(NotFoundError::new())
+    }
+}
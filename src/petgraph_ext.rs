@@ -0,0 +1,66 @@
+use petgraph::graph::{EdgeIndex, Graph, IndexType, NodeIndex};
+use petgraph::stable_graph::StableGraph;
+use petgraph::EdgeType;
+
+use crate::NotFoundByKey;
+
+/// A type alias for the error returned by [`GraphExt::find_edge_required`]: the key is the pair of node indices
+pub type EdgeNotFound<E, Ix> = NotFoundByKey<E, (NodeIndex<Ix>, NodeIndex<Ix>)>;
+
+/// An extension trait for the graphs of `petgraph` that returns keyed errors instead of `None` (feature `petgraph`)
+///
+/// Implemented for [`Graph`] and [`StableGraph`].
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::graph::{EdgeIndex, Graph, NodeIndex};
+/// use not_found_error::{GraphExt, NotFoundByKey};
+///
+/// let mut graph = Graph::<&str, u32>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let edge = graph.add_edge(a, b, 7);
+///
+/// assert_eq!(graph.node_weight_required(a), Ok(&"a"));
+/// assert_eq!(graph.node_weight_required(NodeIndex::new(2)), Err(NotFoundByKey::new(NodeIndex::new(2))));
+/// assert_eq!(graph.edge_weight_required(edge), Ok(&7));
+/// assert_eq!(graph.edge_weight_required(EdgeIndex::new(1)), Err(NotFoundByKey::new(EdgeIndex::new(1))));
+/// assert_eq!(graph.find_edge_required(a, b), Ok(edge));
+/// assert_eq!(graph.find_edge_required(b, a), Err(NotFoundByKey::new((b, a))));
+/// ```
+pub trait GraphExt<N, E, Ix: IndexType> {
+    /// Returns the weight of the node, or an error that contains the node index.
+    fn node_weight_required(&self, index: NodeIndex<Ix>) -> Result<&N, NotFoundByKey<N, NodeIndex<Ix>>>;
+
+    /// Returns the weight of the edge, or an error that contains the edge index.
+    fn edge_weight_required(&self, index: EdgeIndex<Ix>) -> Result<&E, NotFoundByKey<E, EdgeIndex<Ix>>>;
+
+    /// Returns the index of an edge from `a` to `b` (in either direction for undirected graphs), or an error that contains both node indices.
+    fn find_edge_required(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Result<EdgeIndex<Ix>, EdgeNotFound<E, Ix>>;
+}
+
+macro_rules! impl_graph_ext {
+    ($($graph:ident),+) => {
+        $(
+            impl<N, E, Ty: EdgeType, Ix: IndexType> GraphExt<N, E, Ix> for $graph<N, E, Ty, Ix> {
+                #[inline(always)]
+                fn node_weight_required(&self, index: NodeIndex<Ix>) -> Result<&N, NotFoundByKey<N, NodeIndex<Ix>>> {
+                    self.node_weight(index).ok_or(NotFoundByKey::new(index))
+                }
+
+                #[inline(always)]
+                fn edge_weight_required(&self, index: EdgeIndex<Ix>) -> Result<&E, NotFoundByKey<E, EdgeIndex<Ix>>> {
+                    self.edge_weight(index).ok_or(NotFoundByKey::new(index))
+                }
+
+                #[inline(always)]
+                fn find_edge_required(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Result<EdgeIndex<Ix>, EdgeNotFound<E, Ix>> {
+                    self.find_edge(a, b).ok_or(NotFoundByKey::new((a, b)))
+                }
+            }
+        )+
+    };
+}
+
+impl_graph_ext!(Graph, StableGraph);
@@ -0,0 +1,44 @@
+//! Re-exports the extension traits and the most common types.
+//!
+//! ```
+//! use not_found_error::prelude::*;
+//!
+//! fn first_even(numbers: &[i32]) -> NotFoundResult<&i32> {
+//!     numbers.iter().find(|n| *n % 2 == 0).require()
+//! }
+//!
+//! assert_eq!(first_even(&[1, 2, 3]), Ok(&2));
+//! ```
+
+#[cfg(feature = "generational-arena")]
+pub use crate::ArenaExt;
+#[cfg(feature = "clap")]
+pub use crate::ArgMatchesExt;
+#[cfg(feature = "dashmap")]
+pub use crate::DashMapExt;
+#[cfg(feature = "futures")]
+pub use crate::FutureRequireExt;
+#[cfg(feature = "petgraph")]
+pub use crate::GraphExt;
+#[cfg(feature = "hecs")]
+pub use crate::HecsWorldExt;
+#[cfg(feature = "lru")]
+pub use crate::LruCacheExt;
+#[cfg(feature = "slotmap")]
+pub use crate::SlotMapExt;
+#[cfg(feature = "futures")]
+pub use crate::StreamRequireExt;
+#[cfg(feature = "im")]
+pub use crate::VectorExt;
+pub use crate::{not_found, require, FindError, FindResult, NotFoundByKey, NotFoundError, NotFoundResult};
+pub use crate::{AnyExt, AnyNotFound, BTreeMapExt, BinaryHeapExt, EntryExt, ExactSizeIteratorExt, ExpectFound, Find, FindAsync, FlattenRequire, HashMapExt, IteratorExt, Locate, MapExt, MapNotFound, OkOrNotFound, OkOrNotFoundBy, OnceExt, OptionMutExt, Optional, OrFind, OrNotFound, PathExt, PeekableExt, ReceiverExt, Require, RequireAll, RequireFlatten, RequireGet, RequireInto, RequirePoll, RequireRef, RequireTranspose, RequireWith, SetExt, SliceRequire, StrRequire, TryOptional, UnwrapFound, VecDequeExt, VecExt, WeakExt, ZipRequire};
+#[cfg(feature = "bevy")]
+pub use crate::{AssetsExt, BevyWorldExt, QueryExt};
+#[cfg(feature = "tokio")]
+pub use crate::{BroadcastReceiverExt, WatchReceiverExt};
+#[cfg(feature = "indexmap")]
+pub use crate::{IndexMapExt, IndexSetExt};
+#[cfg(feature = "moka")]
+pub use crate::{MokaCacheExt, MokaFutureCacheExt};
+#[cfg(feature = "specs")]
+pub use crate::{ReadStorageExt, WriteStorageExt};
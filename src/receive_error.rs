@@ -0,0 +1,54 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::{mark_not_found, AnyNotFound, NotFoundError};
+
+/// Represents an error returned by [`ReceiverExt`](crate::ReceiverExt): either no message was available, or the channel is disconnected.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{NotFoundError, ReceiveError};
+///
+/// let error = ReceiveError::<i32>::NotFound(NotFoundError::new());
+/// assert_eq!(error.to_string(), "i32 not found");
+///
+/// let error = ReceiveError::<i32>::Disconnected;
+/// assert_eq!(error.to_string(), "channel of i32 is disconnected");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub enum ReceiveError<T> {
+    NotFound(NotFoundError<T>),
+    Disconnected,
+}
+
+impl<T> Display for ReceiveError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReceiveError::NotFound(error) => Display::fmt(error, f),
+            ReceiveError::Disconnected => write!(f, "channel of {} is disconnected", type_name::<T>()),
+        }
+    }
+}
+
+impl<T: Debug> Error for ReceiveError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReceiveError::NotFound(_) => mark_not_found(None),
+            _ => None,
+        }
+    }
+}
+
+impl<T> AnyNotFound for ReceiveError<T> {
+    fn is_not_found(&self) -> bool {
+        matches!(self, ReceiveError::NotFound(_))
+    }
+}
+
+impl<T> From<NotFoundError<T>> for ReceiveError<T> {
+    fn from(error: NotFoundError<T>) -> Self {
+        Self::NotFound(error)
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::mpsc::{Receiver, RecvTimeoutError, TryRecvError};
+use std::time::Duration;
+
+use crate::{NotFoundError, ReceiveError};
+
+/// An extension trait for [`Receiver`] that reports a missing message as [`ReceiveError::NotFound`]
+///
+/// Useful for polling loops that treat the absence of a message as an error.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::mpsc::channel;
+/// use std::time::Duration;
+/// use not_found_error::{NotFoundError, ReceiveError, ReceiverExt};
+///
+/// let (sender, receiver) = channel();
+/// assert_eq!(receiver.try_recv_required(), Err(ReceiveError::NotFound(NotFoundError::<u32>::new())));
+///
+/// sender.send(1).unwrap();
+/// assert_eq!(receiver.try_recv_required(), Ok(1));
+/// assert_eq!(receiver.recv_timeout_required(Duration::from_millis(1)), Err(ReceiveError::NotFound(NotFoundError::new())));
+///
+/// drop(sender);
+/// assert_eq!(receiver.try_recv_required(), Err(ReceiveError::Disconnected));
+/// ```
+pub trait ReceiverExt {
+    type T;
+
+    /// Returns the next message, or an error if the channel is empty or disconnected.
+    fn try_recv_required(&self) -> Result<Self::T, ReceiveError<Self::T>>;
+
+    /// Waits for the next message, or returns an error if the timeout elapses or the channel is disconnected.
+    fn recv_timeout_required(&self, timeout: Duration) -> Result<Self::T, ReceiveError<Self::T>>;
+}
+
+impl<T> ReceiverExt for Receiver<T> {
+    type T = T;
+
+    #[inline(always)]
+    fn try_recv_required(&self) -> Result<T, ReceiveError<T>> {
+        self.try_recv().map_err(|error| match error {
+            TryRecvError::Empty => ReceiveError::NotFound(NotFoundError::new()),
+            TryRecvError::Disconnected => ReceiveError::Disconnected,
+        })
+    }
+
+    #[inline(always)]
+    fn recv_timeout_required(&self, timeout: Duration) -> Result<T, ReceiveError<T>> {
+        self.recv_timeout(timeout).map_err(|error| match error {
+            RecvTimeoutError::Timeout => ReceiveError::NotFound(NotFoundError::new()),
+            RecvTimeoutError::Disconnected => ReceiveError::Disconnected,
+        })
+    }
+}
@@ -0,0 +1,116 @@
+//! Aggregation of multiple not-found errors.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::{mark_not_found, AnyNotFound, DynNotFoundError};
+
+/// Collects multiple [`DynNotFoundError`]s (possibly about different types and keys).
+///
+/// Useful in batch jobs that need to report every missing item instead of failing on the first one.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{DynNotFoundError, NotFoundError};
+/// use not_found_error::report::NotFoundReport;
+///
+/// let mut report = NotFoundReport::new();
+/// assert!(report.is_empty());
+///
+/// report.push(NotFoundError::<i32>::new());
+/// report.extend([DynNotFoundError::with_key::<String>(&"alice"), DynNotFoundError::with_key::<String>(&"bob")]);
+///
+/// assert_eq!(report.len(), 3);
+/// assert_eq!(report.iter().filter(|error| error.type_name == "i32").count(), 1);
+/// assert_eq!(report.to_string(), "3 items not found:\n- i32 not found\n- alloc::string::String not found by key \"alice\"\n- alloc::string::String not found by key \"bob\"");
+/// ```
+#[derive(Eq, PartialEq, Hash, Clone, Default, Debug)]
+pub struct NotFoundReport {
+    pub errors: Vec<DynNotFoundError>,
+}
+
+impl NotFoundReport {
+    /// Creates an empty `NotFoundReport`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an error to the report.
+    pub fn push(&mut self, error: impl Into<DynNotFoundError>) {
+        self.errors.push(error.into())
+    }
+
+    /// Returns `true` if the report contains no errors.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the number of errors in the report.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns an iterator over the errors in the report.
+    pub fn iter(&self) -> std::slice::Iter<'_, DynNotFoundError> {
+        self.errors.iter()
+    }
+}
+
+impl<E: Into<DynNotFoundError>> Extend<E> for NotFoundReport {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        self.errors.extend(iter.into_iter().map(Into::into))
+    }
+}
+
+impl<E: Into<DynNotFoundError>> FromIterator<E> for NotFoundReport {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        Self {
+            errors: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl IntoIterator for NotFoundReport {
+    type Item = DynNotFoundError;
+    type IntoIter = std::vec::IntoIter<DynNotFoundError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a NotFoundReport {
+    type Item = &'a DynNotFoundError;
+    type IntoIter = std::slice::Iter<'a, DynNotFoundError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+impl Display for NotFoundReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let len = self.errors.len();
+        let noun = if len == 1 { "item" } else { "items" };
+        write!(f, "{len} {noun} not found")?;
+        if !self.errors.is_empty() {
+            write!(f, ":")?;
+        }
+        self.errors
+            .iter()
+            .try_for_each(|error| write!(f, "\n- {error}"))
+    }
+}
+
+impl Error for NotFoundReport {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        mark_not_found(None)
+    }
+}
+
+impl AnyNotFound for NotFoundReport {
+    fn is_not_found(&self) -> bool {
+        true
+    }
+}
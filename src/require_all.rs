@@ -0,0 +1,142 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::{mark_not_found, AnyNotFound};
+
+/// Represents an error indicating that some elements of a tuple of options were not found.
+///
+/// `T` is the tuple of the unwrapped element types, `missing` holds the indices of the elements that were `None`.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{EitherNotFound, RequireAll};
+///
+/// let error: EitherNotFound<i32, char> = (Some(1), None::<char>).require_all().unwrap_err();
+/// assert_eq!(error.missing, vec![1]);
+/// assert!(error.is_missing(1));
+/// assert_eq!(error.to_string(), "(i32, char) not found: missing elements at indices [1]");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Debug)]
+pub struct TupleNotFoundError<T> {
+    pub missing: Vec<usize>,
+    pub phantom: PhantomData<T>,
+}
+
+impl<T> TupleNotFoundError<T> {
+    /// Creates a new `TupleNotFoundError` with the given indices of missing elements.
+    pub fn new(missing: Vec<usize>) -> Self {
+        Self {
+            missing,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns `true` if the element at `index` was missing.
+    pub fn is_missing(&self, index: usize) -> bool {
+        self.missing.contains(&index)
+    }
+}
+
+impl<T> Display for TupleNotFoundError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} not found: missing elements at indices {:?}", type_name::<T>(), self.missing)
+    }
+}
+
+impl<T: Debug> Error for TupleNotFoundError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        mark_not_found(None)
+    }
+}
+
+impl<T> AnyNotFound for TupleNotFoundError<T> {
+    fn is_not_found(&self) -> bool {
+        true
+    }
+}
+
+/// A type alias for the error returned by [`RequireAll::require_all`] on a pair of options
+pub type EitherNotFound<A, B> = TupleNotFoundError<(A, B)>;
+
+/// A type alias for the error returned by [`ZipRequire::zip_require`]
+pub type ZipNotFound<A, B> = EitherNotFound<A, B>;
+
+/// An extension trait for `Option<A>` to combine it with `Option<B>` into `Result<(A, B), ZipNotFound<A, B>>`
+///
+/// The error says whether `A`, `B`, or both were missing.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::ZipRequire;
+///
+/// assert_eq!(Some(1).zip_require(Some('a')), Ok((1, 'a')));
+///
+/// let error = None::<i32>.zip_require(None::<char>).unwrap_err();
+/// assert!(error.is_missing(0) && error.is_missing(1));
+/// ```
+pub trait ZipRequire {
+    type T;
+
+    fn zip_require<B>(self, other: Option<B>) -> Result<(Self::T, B), ZipNotFound<Self::T, B>>;
+}
+
+impl<A> ZipRequire for Option<A> {
+    type T = A;
+
+    #[inline(always)]
+    fn zip_require<B>(self, other: Option<B>) -> Result<(A, B), ZipNotFound<A, B>> {
+        (self, other).require_all()
+    }
+}
+
+/// An extension trait for tuples of `Option`s to convert them to `Result<(A, B, ...), TupleNotFoundError<(A, B, ...)>>`
+///
+/// Implemented for tuples of up to 8 elements.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{RequireAll, TupleNotFoundError};
+///
+/// assert_eq!((Some(1), Some('a'), Some("b")).require_all(), Ok((1, 'a', "b")));
+/// assert_eq!((None::<i32>, Some('a'), None::<&str>).require_all(), Err(TupleNotFoundError::new(vec![0, 2])));
+/// ```
+pub trait RequireAll {
+    type Output;
+
+    fn require_all(self) -> Result<Self::Output, TupleNotFoundError<Self::Output>>;
+}
+
+macro_rules! impl_require_all_for_tuple {
+    ($($ty:ident $var:ident $index:tt),+) => {
+        impl<$($ty),+> RequireAll for ($(Option<$ty>,)+) {
+            type Output = ($($ty,)+);
+
+            fn require_all(self) -> Result<Self::Output, TupleNotFoundError<Self::Output>> {
+                match self {
+                    ($(Some($var),)+) => Ok(($($var,)+)),
+                    _ => {
+                        let missing = [$(self.$index.is_none()),+]
+                            .into_iter()
+                            .enumerate()
+                            .filter_map(|(index, is_none)| is_none.then_some(index))
+                            .collect();
+                        Err(TupleNotFoundError::new(missing))
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_require_all_for_tuple!(A a 0, B b 1);
+impl_require_all_for_tuple!(A a 0, B b 1, C c 2);
+impl_require_all_for_tuple!(A a 0, B b 1, C c 2, D d 3);
+impl_require_all_for_tuple!(A a 0, B b 1, C c 2, D d 3, E e 4);
+impl_require_all_for_tuple!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5);
+impl_require_all_for_tuple!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6);
+impl_require_all_for_tuple!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6, H h 7);
@@ -0,0 +1,59 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+use crate::NotFoundError;
+
+/// An extension trait for maps that returns `NotFoundError<V>` instead of `None` on lookup
+///
+/// Implemented for [`HashMap`] and [`BTreeMap`]. Like [`HashMap::get`], accepts any borrowed form of the key.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use not_found_error::{NotFoundError, RequireGet};
+///
+/// let mut ages = HashMap::from([("alice".to_string(), 30)]);
+///
+/// assert_eq!(ages.get_required("alice"), Ok(&30));
+/// assert_eq!(ages.get_required("bob"), Err(NotFoundError::new()));
+///
+/// *ages.get_mut_required("alice").unwrap() += 1;
+/// assert_eq!(ages["alice"], 31);
+/// ```
+pub trait RequireGet<Q: ?Sized> {
+    type V;
+
+    fn get_required(&self, key: &Q) -> Result<&Self::V, NotFoundError<Self::V>>;
+
+    fn get_mut_required(&mut self, key: &Q) -> Result<&mut Self::V, NotFoundError<Self::V>>;
+}
+
+impl<K: Borrow<Q> + Hash + Eq, V, S: BuildHasher, Q: ?Sized + Hash + Eq> RequireGet<Q> for HashMap<K, V, S> {
+    type V = V;
+
+    #[inline(always)]
+    fn get_required(&self, key: &Q) -> Result<&V, NotFoundError<V>> {
+        self.get(key).ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self, key: &Q) -> Result<&mut V, NotFoundError<V>> {
+        self.get_mut(key).ok_or(NotFoundError::new())
+    }
+}
+
+impl<K: Borrow<Q> + Ord, V, Q: ?Sized + Ord> RequireGet<Q> for BTreeMap<K, V> {
+    type V = V;
+
+    #[inline(always)]
+    fn get_required(&self, key: &Q) -> Result<&V, NotFoundError<V>> {
+        self.get(key).ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self, key: &Q) -> Result<&mut V, NotFoundError<V>> {
+        self.get_mut(key).ok_or(NotFoundError::new())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::NotFoundError;
+
+/// An extension trait for `Option<T>` to convert it to `Result<U, NotFoundError<U>>` where `T: Into<U>`
+///
+/// Useful when a raw row or DTO is looked up and immediately converted into a domain type.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{NotFoundError, RequireInto};
+///
+/// # #[derive(PartialEq, Debug)]
+/// # struct UserRow { name: &'static str }
+/// #[derive(PartialEq, Debug)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// impl From<UserRow> for User {
+///     fn from(row: UserRow) -> Self {
+///         Self { name: row.name.into() }
+///     }
+/// }
+///
+/// let user = Some(UserRow { name: "alice" }).require_into::<User>();
+/// assert_eq!(user, Ok(User { name: "alice".into() }));
+///
+/// let user = None::<UserRow>.require_into::<User>();
+/// assert_eq!(user, Err(NotFoundError::new()));
+/// ```
+pub trait RequireInto {
+    type T;
+
+    fn require_into<U>(self) -> Result<U, NotFoundError<U>>
+    where
+        Self::T: Into<U>;
+}
+
+impl<T> RequireInto for Option<T> {
+    type T = T;
+
+    #[inline(always)]
+    fn require_into<U>(self) -> Result<U, NotFoundError<U>>
+    where
+        T: Into<U>,
+    {
+        self.map(Into::into).ok_or(NotFoundError::new())
+    }
+}
@@ -0,0 +1,32 @@
+use std::task::Poll;
+
+use crate::NotFoundError;
+
+/// An extension trait for `Poll<Option<T>>` to convert it to `Poll<Result<T, NotFoundError<T>>>`
+///
+/// Useful in hand-written `Stream` and `Future` implementations that treat the end of a stream as "not found".
+///
+/// # Examples
+///
+/// ```
+/// use std::task::Poll;
+/// use not_found_error::{NotFoundError, RequirePoll};
+///
+/// assert_eq!(Poll::Ready(Some(1)).require(), Poll::Ready(Ok(1)));
+/// assert_eq!(Poll::Ready(None::<i32>).require(), Poll::Ready(Err(NotFoundError::new())));
+/// assert_eq!(Poll::Pending::<Option<i32>>.require(), Poll::Pending);
+/// ```
+pub trait RequirePoll {
+    type T;
+
+    fn require(self) -> Poll<Result<Self::T, NotFoundError<Self::T>>>;
+}
+
+impl<T> RequirePoll for Poll<Option<T>> {
+    type T = T;
+
+    #[inline(always)]
+    fn require(self) -> Poll<Result<T, NotFoundError<T>>> {
+        self.map(|option| option.ok_or(NotFoundError::new()))
+    }
+}
@@ -0,0 +1,49 @@
+use crate::NotFoundError;
+
+/// An extension trait for `Option<&T>` that labels the error by the owned type `T` (not `&T`)
+///
+/// Useful because error enums usually contain `NotFoundError<T>`, not `NotFoundError<&T>`.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{NotFoundError, RequireRef};
+///
+/// let names = vec![String::from("alice")];
+///
+/// let name: Result<&String, NotFoundError<String>> = names.first().require_deref();
+/// assert_eq!(name, Ok(&String::from("alice")));
+///
+/// let name: Result<String, NotFoundError<String>> = names.first().require_owned();
+/// assert_eq!(name, Ok(String::from("alice")));
+///
+/// assert_eq!(names.get(1).require_owned(), Err(NotFoundError::<String>::new()));
+/// ```
+pub trait RequireRef<'a> {
+    type T;
+
+    /// Converts `Option<&T>` to `Result<&T, NotFoundError<T>>`.
+    fn require_deref(self) -> Result<&'a Self::T, NotFoundError<Self::T>>;
+
+    /// Converts `Option<&T>` to `Result<T, NotFoundError<T>>` by cloning the value.
+    fn require_owned(self) -> Result<Self::T, NotFoundError<Self::T>>
+    where
+        Self::T: Clone;
+}
+
+impl<'a, T> RequireRef<'a> for Option<&'a T> {
+    type T = T;
+
+    #[inline(always)]
+    fn require_deref(self) -> Result<&'a T, NotFoundError<T>> {
+        self.ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn require_owned(self) -> Result<T, NotFoundError<T>>
+    where
+        T: Clone,
+    {
+        self.cloned().ok_or(NotFoundError::new())
+    }
+}
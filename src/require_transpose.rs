@@ -0,0 +1,103 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::{mark_not_found, AnyNotFound, NotFoundError};
+
+/// Represents an error indicating that a value was either absent or failed to load.
+///
+/// Returned by [`RequireTranspose::require_transpose`].
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use not_found_error::{NotFoundError, RequireOr};
+///
+/// let error = RequireOr::<io::Error, i32>::NotFound(NotFoundError::new());
+/// assert_eq!(error.to_string(), "i32 not found");
+///
+/// // Converts into `io::Error` with `io::ErrorKind::NotFound`
+/// let error: io::Error = error.into();
+/// assert_eq!(error.kind(), io::ErrorKind::NotFound);
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub enum RequireOr<E, T> {
+    NotFound(NotFoundError<T>),
+    Other(E),
+}
+
+impl<E: Display, T> Display for RequireOr<E, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequireOr::NotFound(error) => Display::fmt(error, f),
+            RequireOr::Other(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<E: Error + 'static, T: Debug> Error for RequireOr<E, T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RequireOr::NotFound(_) => mark_not_found(None),
+            RequireOr::Other(error) => Some(error),
+        }
+    }
+}
+
+impl<E, T> AnyNotFound for RequireOr<E, T> {
+    fn is_not_found(&self) -> bool {
+        matches!(self, RequireOr::NotFound(_))
+    }
+}
+
+impl<E, T> From<NotFoundError<T>> for RequireOr<E, T> {
+    fn from(error: NotFoundError<T>) -> Self {
+        Self::NotFound(error)
+    }
+}
+
+impl<T: Debug + Send + Sync + 'static> From<RequireOr<std::io::Error, T>> for std::io::Error {
+    fn from(error: RequireOr<std::io::Error, T>) -> Self {
+        match error {
+            RequireOr::NotFound(error) => std::io::Error::new(std::io::ErrorKind::NotFound, error),
+            RequireOr::Other(error) => error,
+        }
+    }
+}
+
+/// An extension trait for `Option<Result<T, E>>` to convert it to `Result<T, RequireOr<E, T>>`
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{NotFoundError, RequireOr, RequireTranspose};
+///
+/// let value: Option<Result<i32, String>> = Some(Ok(1));
+/// assert_eq!(value.require_transpose(), Ok(1));
+///
+/// let value: Option<Result<i32, String>> = Some(Err("connection lost".into()));
+/// assert_eq!(value.require_transpose(), Err(RequireOr::Other("connection lost".into())));
+///
+/// let value: Option<Result<i32, String>> = None;
+/// assert_eq!(value.require_transpose(), Err(RequireOr::NotFound(NotFoundError::new())));
+/// ```
+pub trait RequireTranspose {
+    type T;
+    type E;
+
+    fn require_transpose(self) -> Result<Self::T, RequireOr<Self::E, Self::T>>;
+}
+
+impl<T, E> RequireTranspose for Option<Result<T, E>> {
+    type T = T;
+    type E = E;
+
+    #[inline(always)]
+    fn require_transpose(self) -> Result<T, RequireOr<E, T>> {
+        match self {
+            Some(Ok(value)) => Ok(value),
+            Some(Err(error)) => Err(RequireOr::Other(error)),
+            None => Err(RequireOr::NotFound(NotFoundError::new())),
+        }
+    }
+}
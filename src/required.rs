@@ -0,0 +1,102 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::NotFoundError;
+
+/// A value that is guaranteed to be present.
+///
+/// Use it to encode "this option has already been checked" in the type system instead of re-checking `Option<T>` in multiple places.
+///
+/// With the `serde` feature, `Required<T>` serializes as `T` and fails to deserialize from `null` with a `NotFoundError<T>` message.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{NotFoundError, Required};
+///
+/// let name = Required::try_from(Some("alice")).unwrap();
+/// assert_eq!(name.len(), 5);
+/// assert_eq!(name.into_inner(), "alice");
+///
+/// assert_eq!(Required::<i32>::try_from(None), Err(NotFoundError::new()));
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct Required<T>(T);
+
+impl<T> Required<T> {
+    /// Creates a new `Required` from a present value.
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> TryFrom<Option<T>> for Required<T> {
+    type Error = NotFoundError<T>;
+
+    fn try_from(option: Option<T>) -> Result<Self, Self::Error> {
+        option.map(Self).ok_or(NotFoundError::new())
+    }
+}
+
+impl<T> From<Required<T>> for Option<T> {
+    fn from(required: Required<T>) -> Self {
+        Some(required.0)
+    }
+}
+
+impl<T> Deref for Required<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Required<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> AsRef<T> for Required<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Required;
+    use crate::NotFoundError;
+
+    impl<T: Serialize> Serialize for Required<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize(serializer)
+        }
+    }
+
+    /// Deserializes `T`, failing with a `NotFoundError<T>` message on `null`
+    ///
+    /// ```
+    /// # use not_found_error::Required;
+    /// let value: Required<i32> = serde_json::from_str("42").unwrap();
+    /// assert_eq!(*value, 42);
+    ///
+    /// let error = serde_json::from_str::<Required<i32>>("null").unwrap_err();
+    /// assert_eq!(error.to_string(), "i32 not found");
+    /// ```
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Required<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Option::<T>::deserialize(deserializer)?
+                .try_into()
+                .map_err(|error: NotFoundError<T>| D::Error::custom(error))
+        }
+    }
+}
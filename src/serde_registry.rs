@@ -0,0 +1,144 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error_id::serde_impls::Repr;
+use crate::{mark_not_found, AnyNotFound, ErrorId, NotFoundError};
+
+/// A boxed error returned by [`SerdeRegistry::deserialize`]
+pub type BoxedNotFoundError = Box<dyn Error + Send + Sync + 'static>;
+
+/// A runtime registry that reconstructs typed not-found errors from their serialized representation.
+///
+/// A serialized `NotFoundError<T>` contains only the [`ErrorId`] of `T`, so the receiving side needs to know which Rust type corresponds to each identifier.
+/// Register the types with [`SerdeRegistry::register`], then deserialize with [`SerdeRegistry::deserialize`] (or use the registry as a [`DeserializeSeed`]).
+///
+/// Errors with unknown identifiers are deserialized into [`UnknownNotFoundError`], which keeps the serialized message.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{ErrorId, NotFoundError, SerdeRegistry, UnknownNotFoundError};
+///
+/// #[derive(Debug)]
+/// struct User;
+///
+/// impl ErrorId for User {
+///     const ERROR_ID: &'static str = "User";
+/// }
+///
+/// let mut registry = SerdeRegistry::new();
+/// registry.register::<User>();
+///
+/// let json = serde_json::to_string(&NotFoundError::<User>::new()).unwrap();
+/// let error = registry.deserialize(&mut serde_json::Deserializer::from_str(&json)).unwrap();
+/// assert!(error.downcast_ref::<NotFoundError<User>>().is_some());
+///
+/// let json = r#"{ "error_id": "E_ORDER_NOT_FOUND", "message": "Order not found" }"#;
+/// let error = registry.deserialize(&mut serde_json::Deserializer::from_str(json)).unwrap();
+/// assert_eq!(error.to_string(), "Order not found");
+/// assert_eq!(error.downcast_ref::<UnknownNotFoundError>(), Some(&UnknownNotFoundError::new("E_ORDER_NOT_FOUND", "Order not found")));
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct SerdeRegistry {
+    constructors: HashMap<&'static str, fn() -> BoxedNotFoundError>,
+}
+
+impl SerdeRegistry {
+    /// Creates an empty `SerdeRegistry`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `NotFoundError<T>` under `T::ERROR_ID`.
+    ///
+    /// Registering the same identifier twice replaces the previous type.
+    pub fn register<T: ErrorId + Debug + Send + Sync + 'static>(&mut self) -> &mut Self {
+        self.constructors
+            .insert(T::ERROR_ID, || Box::new(NotFoundError::<T>::new()));
+        self
+    }
+
+    /// Returns `true` if a type with the given identifier has been registered.
+    pub fn contains(&self, error_id: &str) -> bool {
+        self.constructors.contains_key(error_id)
+    }
+
+    /// Deserializes a not-found error, reconstructing its type if the identifier has been registered.
+    pub fn deserialize<'de, D: Deserializer<'de>>(&self, deserializer: D) -> Result<BoxedNotFoundError, D::Error> {
+        let repr = Repr::deserialize(deserializer)?;
+        Ok(match self.constructors.get(repr.error_id.as_ref()) {
+            Some(constructor) => constructor(),
+            None => Box::new(UnknownNotFoundError::new(repr.error_id, repr.message)),
+        })
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for &SerdeRegistry {
+    type Value = BoxedNotFoundError;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        SerdeRegistry::deserialize(self, deserializer)
+    }
+}
+
+/// Represents a serialized not-found error whose identifier has not been registered in a [`SerdeRegistry`].
+///
+/// Displays the serialized message and serializes back into the same representation.
+///
+/// ```
+/// use not_found_error::UnknownNotFoundError;
+///
+/// let error = UnknownNotFoundError::new("E_ORDER_NOT_FOUND", "Order not found");
+/// assert_eq!(error.to_string(), "Order not found");
+/// assert_eq!(serde_json::to_string(&error).unwrap(), r#"{"error_id":"E_ORDER_NOT_FOUND","message":"Order not found"}"#);
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Debug)]
+pub struct UnknownNotFoundError {
+    /// The serialized [`ErrorId`] of the missing type
+    pub error_id: String,
+    /// The serialized message (the `Display` representation on the sending side)
+    pub message: String,
+}
+
+impl UnknownNotFoundError {
+    /// Creates a new `UnknownNotFoundError`.
+    pub fn new(error_id: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            error_id: error_id.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for UnknownNotFoundError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for UnknownNotFoundError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        mark_not_found(None)
+    }
+}
+
+impl AnyNotFound for UnknownNotFoundError {
+    fn is_not_found(&self) -> bool {
+        true
+    }
+}
+
+impl Serialize for UnknownNotFoundError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Repr {
+            error_id: Cow::Borrowed(&self.error_id),
+            message: Cow::Borrowed(&self.message),
+        }
+        .serialize(serializer)
+    }
+}
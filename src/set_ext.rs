@@ -0,0 +1,65 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeSet, HashSet};
+use std::hash::{BuildHasher, Hash};
+
+use crate::NotFoundByKey;
+
+/// An extension trait for sets that returns keyed errors instead of `None`
+///
+/// Implemented for [`HashSet`] and [`BTreeSet`]. Like [`HashSet::get`], accepts any borrowed form of the value; the error contains an owned copy of it.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use not_found_error::{NotFoundByKey, SetExt};
+///
+/// let mut interned = HashSet::from(["alice".to_string()]);
+///
+/// assert_eq!(interned.get_required("alice"), Ok(&"alice".to_string()));
+/// assert_eq!(interned.get_required("bob"), Err(NotFoundByKey::new("bob".to_string())));
+///
+/// assert_eq!(interned.take_required("alice"), Ok("alice".to_string()));
+/// assert!(interned.is_empty());
+/// ```
+pub trait SetExt<Q: ?Sized + ToOwned> {
+    type T;
+
+    /// Returns a reference to the value in the set that is equal to the given one, or an error that contains the given value.
+    fn get_required(&self, value: &Q) -> Result<&Self::T, NotFoundByKey<Self::T, Q::Owned>>;
+
+    /// Removes and returns the value in the set that is equal to the given one, or returns an error that contains the given value.
+    fn take_required(&mut self, value: &Q) -> Result<Self::T, NotFoundByKey<Self::T, Q::Owned>>;
+}
+
+impl<T: Borrow<Q> + Hash + Eq, S: BuildHasher, Q: ?Sized + Hash + Eq + ToOwned> SetExt<Q> for HashSet<T, S> {
+    type T = T;
+
+    #[inline(always)]
+    fn get_required(&self, value: &Q) -> Result<&T, NotFoundByKey<T, Q::Owned>> {
+        self.get(value)
+            .ok_or_else(|| NotFoundByKey::new(value.to_owned()))
+    }
+
+    #[inline(always)]
+    fn take_required(&mut self, value: &Q) -> Result<T, NotFoundByKey<T, Q::Owned>> {
+        self.take(value)
+            .ok_or_else(|| NotFoundByKey::new(value.to_owned()))
+    }
+}
+
+impl<T: Borrow<Q> + Ord, Q: ?Sized + Ord + ToOwned> SetExt<Q> for BTreeSet<T> {
+    type T = T;
+
+    #[inline(always)]
+    fn get_required(&self, value: &Q) -> Result<&T, NotFoundByKey<T, Q::Owned>> {
+        self.get(value)
+            .ok_or_else(|| NotFoundByKey::new(value.to_owned()))
+    }
+
+    #[inline(always)]
+    fn take_required(&mut self, value: &Q) -> Result<T, NotFoundByKey<T, Q::Owned>> {
+        self.take(value)
+            .ok_or_else(|| NotFoundByKey::new(value.to_owned()))
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{NotFoundByKey, NotFoundError};
+
+/// A type alias for the result of [`SliceRequire::split_first_required`] and [`SliceRequire::split_last_required`]
+pub type SplitResult<'a, T> = Result<(&'a T, &'a [T]), NotFoundError<T>>;
+
+/// An extension trait for slices that returns typed errors instead of `None`
+///
+/// The errors are labeled by the element type; [`SliceRequire::get_required`] also reports the index that was out of range.
+pub trait SliceRequire {
+    type T;
+
+    /// Returns the first element of the slice or an error if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundError, SliceRequire};
+    ///
+    /// assert_eq!([1, 2, 3].first_required(), Ok(&1));
+    /// assert_eq!(<[i32]>::first_required(&[]), Err(NotFoundError::new()));
+    /// ```
+    fn first_required(&self) -> Result<&Self::T, NotFoundError<Self::T>>;
+
+    /// Returns a mutable reference to the first element of the slice or an error if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::SliceRequire;
+    ///
+    /// let mut numbers = [1, 2, 3];
+    /// *numbers.first_mut_required().unwrap() = 10;
+    /// assert_eq!(numbers, [10, 2, 3]);
+    /// ```
+    fn first_mut_required(&mut self) -> Result<&mut Self::T, NotFoundError<Self::T>>;
+
+    /// Returns the last element of the slice or an error if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundError, SliceRequire};
+    ///
+    /// assert_eq!([1, 2, 3].last_required(), Ok(&3));
+    /// assert_eq!(Vec::<i32>::new().last_required(), Err(NotFoundError::new()));
+    /// ```
+    fn last_required(&self) -> Result<&Self::T, NotFoundError<Self::T>>;
+
+    /// Returns a mutable reference to the last element of the slice or an error if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::SliceRequire;
+    ///
+    /// let mut numbers = vec![1, 2, 3];
+    /// *numbers.last_mut_required().unwrap() = 30;
+    /// assert_eq!(numbers, [1, 2, 30]);
+    /// ```
+    fn last_mut_required(&mut self) -> Result<&mut Self::T, NotFoundError<Self::T>>;
+
+    /// Returns the element at the given index or an error that contains the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundByKey, SliceRequire};
+    ///
+    /// assert_eq!([1, 2, 3].get_required(1), Ok(&2));
+    /// assert_eq!([1, 2, 3].get_required(5), Err(NotFoundByKey::new(5)));
+    /// assert_eq!([1, 2, 3].get_required(5).unwrap_err().to_string(), "i32 not found by key 5");
+    /// ```
+    fn get_required(&self, index: usize) -> Result<&Self::T, NotFoundByKey<Self::T, usize>>;
+
+    /// Returns a mutable reference to the element at the given index or an error that contains the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundByKey, SliceRequire};
+    ///
+    /// let mut numbers = [1, 2, 3];
+    /// *numbers.get_mut_required(1).unwrap() = 20;
+    /// assert_eq!(numbers, [1, 20, 3]);
+    /// assert_eq!(numbers.get_mut_required(3), Err(NotFoundByKey::new(3)));
+    /// ```
+    fn get_mut_required(&mut self, index: usize) -> Result<&mut Self::T, NotFoundByKey<Self::T, usize>>;
+
+    /// Returns the first element and the rest of the slice or an error if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundError, SliceRequire};
+    ///
+    /// let args = ["run", "--release"];
+    /// assert_eq!(args.split_first_required(), Ok((&"run", &["--release"][..])));
+    /// assert_eq!(<[&str]>::split_first_required(&[]), Err(NotFoundError::new()));
+    /// ```
+    fn split_first_required(&self) -> SplitResult<'_, Self::T>;
+
+    /// Returns the last element and the rest of the slice or an error if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundError, SliceRequire};
+    ///
+    /// let path = ["usr", "bin", "cargo"];
+    /// assert_eq!(path.split_last_required(), Ok((&"cargo", &["usr", "bin"][..])));
+    /// assert_eq!(<[&str]>::split_last_required(&[]), Err(NotFoundError::new()));
+    /// ```
+    fn split_last_required(&self) -> SplitResult<'_, Self::T>;
+}
+
+impl<T> SliceRequire for [T] {
+    type T = T;
+
+    #[inline(always)]
+    fn first_required(&self) -> Result<&T, NotFoundError<T>> {
+        self.first().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn first_mut_required(&mut self) -> Result<&mut T, NotFoundError<T>> {
+        self.first_mut().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn last_required(&self) -> Result<&T, NotFoundError<T>> {
+        self.last().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn last_mut_required(&mut self) -> Result<&mut T, NotFoundError<T>> {
+        self.last_mut().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn get_required(&self, index: usize) -> Result<&T, NotFoundByKey<T, usize>> {
+        self.get(index).ok_or(NotFoundByKey::new(index))
+    }
+
+    #[inline(always)]
+    fn get_mut_required(&mut self, index: usize) -> Result<&mut T, NotFoundByKey<T, usize>> {
+        self.get_mut(index).ok_or(NotFoundByKey::new(index))
+    }
+
+    #[inline(always)]
+    fn split_first_required(&self) -> SplitResult<'_, T> {
+        self.split_first().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn split_last_required(&self) -> SplitResult<'_, T> {
+        self.split_last().ok_or(NotFoundError::new())
+    }
+}
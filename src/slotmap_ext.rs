@@ -0,0 +1,50 @@
+use slotmap::{DenseSlotMap, Key, SecondaryMap, SlotMap};
+
+use crate::NotFoundByKey;
+
+/// An extension trait for the slot maps of the `slotmap` crate that returns keyed errors instead of `None` (feature `slotmap`)
+///
+/// Useful for surfacing stale-handle bugs as descriptive errors.
+///
+/// # Examples
+///
+/// ```
+/// use slotmap::{DefaultKey, SlotMap};
+/// use not_found_error::{NotFoundByKey, SlotMapExt};
+///
+/// let mut nodes = SlotMap::new();
+/// let key = nodes.insert("root");
+///
+/// assert_eq!(nodes.get_required(key), Ok(&"root"));
+/// *nodes.get_mut_required(key).unwrap() = "new root";
+///
+/// nodes.remove(key);
+/// assert_eq!(nodes.get_required(key), Err(NotFoundByKey::<&str, DefaultKey>::new(key)));
+/// ```
+pub trait SlotMapExt<K: Key, V> {
+    /// Returns the value for `key`, or an error that contains the key.
+    fn get_required(&self, key: K) -> Result<&V, NotFoundByKey<V, K>>;
+
+    /// Returns a mutable reference to the value for `key`, or an error that contains the key.
+    fn get_mut_required(&mut self, key: K) -> Result<&mut V, NotFoundByKey<V, K>>;
+}
+
+macro_rules! impl_slot_map_ext {
+    ($($map:ident),+) => {
+        $(
+            impl<K: Key, V> SlotMapExt<K, V> for $map<K, V> {
+                #[inline(always)]
+                fn get_required(&self, key: K) -> Result<&V, NotFoundByKey<V, K>> {
+                    self.get(key).ok_or(NotFoundByKey::new(key))
+                }
+
+                #[inline(always)]
+                fn get_mut_required(&mut self, key: K) -> Result<&mut V, NotFoundByKey<V, K>> {
+                    self.get_mut(key).ok_or(NotFoundByKey::new(key))
+                }
+            }
+        )+
+    };
+}
+
+impl_slot_map_ext!(SlotMap, DenseSlotMap, SecondaryMap);
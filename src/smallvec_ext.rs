@@ -0,0 +1,46 @@
+use smallvec::{Array, SmallVec};
+
+use crate::{NotFoundByKey, NotFoundError, VecExt};
+
+/// Requires the `smallvec` feature.
+///
+/// The slice accessors of [`SliceRequire`](crate::SliceRequire) are available through `Deref<Target = [T]>`.
+///
+/// ```
+/// use smallvec::{smallvec, SmallVec};
+/// use not_found_error::{NotFoundByKey, NotFoundError, SliceRequire, VecExt};
+///
+/// let mut tokens: SmallVec<[&str; 4]> = smallvec!["fn", "main"];
+///
+/// assert_eq!(tokens.first_required(), Ok(&"fn"));
+/// assert_eq!(tokens.get_required(2), Err(NotFoundByKey::new(2)));
+/// assert_eq!(tokens.pop_required(), Ok("main"));
+/// assert_eq!(tokens.remove_required(0), Ok("fn"));
+/// assert_eq!(tokens.pop_required(), Err(NotFoundError::new()));
+/// ```
+impl<A: Array> VecExt for SmallVec<A> {
+    type T = A::Item;
+
+    #[inline(always)]
+    fn pop_required(&mut self) -> Result<A::Item, NotFoundError<A::Item>> {
+        self.pop().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn remove_required(&mut self, index: usize) -> Result<A::Item, NotFoundByKey<A::Item, usize>> {
+        if index < self.len() {
+            Ok(self.remove(index))
+        } else {
+            Err(NotFoundByKey::new(index))
+        }
+    }
+
+    #[inline(always)]
+    fn swap_remove_required(&mut self, index: usize) -> Result<A::Item, NotFoundByKey<A::Item, usize>> {
+        if index < self.len() {
+            Ok(self.swap_remove(index))
+        } else {
+            Err(NotFoundByKey::new(index))
+        }
+    }
+}
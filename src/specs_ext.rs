@@ -0,0 +1,60 @@
+use specs::storage::{GenericReadStorage, GenericWriteStorage, UnprotectedStorage};
+use specs::{Component, Entity};
+
+use crate::NotFoundByKey;
+
+/// A type alias for the mutable access returned by [`WriteStorageExt::get_mut_required`]
+pub type SpecsAccessMut<'a, C> = <<C as Component>::Storage as UnprotectedStorage<C>>::AccessMut<'a>;
+
+/// An extension trait for specs storages (e.g. [`ReadStorage`](specs::ReadStorage)) that returns keyed errors instead of `None` (feature `specs`)
+///
+/// # Examples
+///
+/// ```
+/// use specs::{Builder, Component, VecStorage, World, WorldExt};
+/// use not_found_error::{NotFoundByKey, ReadStorageExt, WriteStorageExt};
+///
+/// #[derive(PartialEq, Debug)]
+/// struct Health(u32);
+///
+/// impl Component for Health {
+///     type Storage = VecStorage<Self>;
+/// }
+///
+/// let mut world = World::new();
+/// world.register::<Health>();
+/// let player = world.create_entity().with(Health(100)).build();
+/// let rock = world.create_entity().build();
+///
+/// world.write_storage::<Health>().get_mut_required(player).unwrap().0 -= 10;
+///
+/// let healths = world.read_storage::<Health>();
+/// assert_eq!(healths.get_required(player), Ok(&Health(90)));
+/// assert_eq!(healths.get_required(rock), Err(NotFoundByKey::new(rock)));
+/// ```
+pub trait ReadStorageExt: GenericReadStorage {
+    /// Returns the component of `entity`, or an error that records the entity.
+    fn get_required(&self, entity: Entity) -> Result<&Self::Component, NotFoundByKey<Self::Component, Entity>>;
+}
+
+impl<S: GenericReadStorage> ReadStorageExt for S {
+    #[inline(always)]
+    fn get_required(&self, entity: Entity) -> Result<&S::Component, NotFoundByKey<S::Component, Entity>> {
+        self.get(entity).ok_or(NotFoundByKey::new(entity))
+    }
+}
+
+/// An extension trait for specs storages (e.g. [`WriteStorage`](specs::WriteStorage)) that returns keyed errors instead of `None` (feature `specs`)
+///
+/// See [`ReadStorageExt`] for an example.
+pub trait WriteStorageExt: GenericWriteStorage {
+    /// Returns mutable access to the component of `entity`, or an error that records the entity.
+    fn get_mut_required(&mut self, entity: Entity) -> Result<SpecsAccessMut<'_, Self::Component>, NotFoundByKey<Self::Component, Entity>>;
+}
+
+impl<S: GenericWriteStorage> WriteStorageExt for S {
+    #[inline(always)]
+    fn get_mut_required(&mut self, entity: Entity) -> Result<SpecsAccessMut<'_, S::Component>, NotFoundByKey<S::Component, Entity>> {
+        self.get_mut(entity).ok_or(NotFoundByKey::new(entity))
+    }
+}
@@ -0,0 +1,79 @@
+use crate::NotFoundError;
+
+/// A trait for types that have a `'static` counterpart that can be used as an error label.
+///
+/// Errors labeled by borrowed data (e.g. `NotFoundError<User<'a>>`) can't be stored in `'static` error enums or converted into `Box<dyn Error + 'static>`.
+/// Implement this trait to relabel them with [`NotFoundError::into_static`].
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{NotFoundError, StaticLabel};
+///
+/// pub struct User<'a> {
+///     pub name: &'a str,
+/// }
+///
+/// impl<'a> StaticLabel for User<'a> {
+///     type Static = User<'static>;
+/// }
+///
+/// fn into_boxed(error: NotFoundError<&User<'_>>) -> Box<dyn std::error::Error> {
+///     Box::new(error.into_static())
+/// }
+/// ```
+pub trait StaticLabel {
+    type Static: ?Sized + 'static;
+}
+
+impl<T: StaticLabel + ?Sized> NotFoundError<T> {
+    /// Changes the type parameter from `T` to the `'static` counterpart of `T`.
+    pub fn into_static(self) -> NotFoundError<T::Static> {
+        NotFoundError::new()
+    }
+}
+
+impl<T: StaticLabel + ?Sized> StaticLabel for &T {
+    type Static = T::Static;
+}
+
+impl<T: StaticLabel + ?Sized> StaticLabel for &mut T {
+    type Static = T::Static;
+}
+
+impl<T: StaticLabel> StaticLabel for [T]
+where
+    T::Static: Sized,
+{
+    type Static = [T::Static];
+}
+
+impl<T: StaticLabel> StaticLabel for Vec<T>
+where
+    T::Static: Sized,
+{
+    type Static = Vec<T::Static>;
+}
+
+impl<T: StaticLabel> StaticLabel for Option<T>
+where
+    T::Static: Sized,
+{
+    type Static = Option<T::Static>;
+}
+
+impl<T: StaticLabel + ?Sized> StaticLabel for Box<T> {
+    type Static = Box<T::Static>;
+}
+
+macro_rules! impl_static_label_for_static_types {
+    ($($ty:ty),+) => {
+        $(
+            impl StaticLabel for $ty {
+                type Static = $ty;
+            }
+        )+
+    };
+}
+
+impl_static_label_for_static_types!(bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, str, String, std::path::Path, std::path::PathBuf, std::ffi::OsStr, std::ffi::OsString);
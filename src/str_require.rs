@@ -0,0 +1,174 @@
+use crate::NotFoundByKey;
+
+/// A marker type for a substring searched with [`StrRequire::find_required`] or [`StrRequire::rfind_required`]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default, Debug)]
+pub struct Substring;
+
+/// A marker type for a prefix required by [`StrRequire::strip_prefix_required`]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default, Debug)]
+pub struct Prefix;
+
+/// A marker type for a suffix required by [`StrRequire::strip_suffix_required`]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default, Debug)]
+pub struct Suffix;
+
+/// A marker type for a separator required by [`StrRequire::split_once_required`] or [`StrRequire::rsplit_once_required`]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default, Debug)]
+pub struct Separator;
+
+/// A marker type for a segment of a string split by a separator (see [`StrRequire::nth_segment_required`])
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default, Debug)]
+pub struct Segment;
+
+/// An extension trait for `str` that returns keyed errors instead of `None`
+///
+/// The errors record the pattern (or the index) that was not found, which replaces the `ok_or_else(|| anyhow!("delimiter not found"))` calls in parsing code.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{NotFoundByKey, StrRequire, Substring};
+///
+/// let line = "key = value";
+/// assert_eq!(line.find_required(" = "), Ok(3));
+/// assert_eq!(line.find_required(": "), Err(NotFoundByKey::<Substring, _>::new(": ".to_string())));
+/// ```
+pub trait StrRequire {
+    /// Returns the byte index of the first occurrence of `pat`, or an error that records `pat`.
+    fn find_required(&self, pat: &str) -> Result<usize, NotFoundByKey<Substring, String>>;
+
+    /// Returns the byte index of the last occurrence of `pat`, or an error that records `pat`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::StrRequire;
+    ///
+    /// assert_eq!("a/b/c".rfind_required("/"), Ok(3));
+    /// assert!("abc".rfind_required("/").is_err());
+    /// ```
+    fn rfind_required(&self, pat: &str) -> Result<usize, NotFoundByKey<Substring, String>>;
+
+    /// Returns the string without `prefix`, or an error that records `prefix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundByKey, Prefix, StrRequire};
+    ///
+    /// assert_eq!("--port=8080".strip_prefix_required("--port="), Ok("8080"));
+    /// assert_eq!("-p".strip_prefix_required("--port="), Err(NotFoundByKey::<Prefix, _>::new("--port=".to_string())));
+    /// ```
+    fn strip_prefix_required(&self, prefix: &str) -> Result<&str, NotFoundByKey<Prefix, String>>;
+
+    /// Returns the string without `suffix`, or an error that records `suffix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::StrRequire;
+    ///
+    /// assert_eq!("main.rs".strip_suffix_required(".rs"), Ok("main"));
+    /// assert!("main.py".strip_suffix_required(".rs").is_err());
+    /// ```
+    fn strip_suffix_required(&self, suffix: &str) -> Result<&str, NotFoundByKey<Suffix, String>>;
+
+    /// Returns the `i`-th character (counted in `char`s, not bytes), or an error that records `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundByKey, StrRequire};
+    ///
+    /// assert_eq!("héllo".char_at_required(1), Ok('é'));
+    /// assert_eq!("héllo".char_at_required(5), Err(NotFoundByKey::new(5)));
+    /// ```
+    fn char_at_required(&self, i: usize) -> Result<char, NotFoundByKey<char, usize>>;
+
+    /// Returns the `n`-th segment of the string split by `sep`, or an error that records both `sep` and `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundByKey, Segment, StrRequire};
+    ///
+    /// let record = "alice:x:1000:1000";
+    /// assert_eq!(record.nth_segment_required(":", 2), Ok("1000"));
+    /// assert_eq!(record.nth_segment_required(":", 7), Err(NotFoundByKey::<Segment, _>::new((":".to_string(), 7))));
+    /// ```
+    fn nth_segment_required(&self, sep: &str, n: usize) -> Result<&str, NotFoundByKey<Segment, (String, usize)>>;
+
+    /// Splits the string on the first occurrence of `sep`, or returns an error that records `sep`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundByKey, Separator, StrRequire};
+    ///
+    /// assert_eq!("Host: example.com".split_once_required(": "), Ok(("Host", "example.com")));
+    /// assert_eq!("Host".split_once_required(": "), Err(NotFoundByKey::<Separator, _>::new(": ".to_string())));
+    /// ```
+    fn split_once_required(&self, sep: &str) -> Result<(&str, &str), NotFoundByKey<Separator, String>>;
+
+    /// Splits the string on the last occurrence of `sep`, or returns an error that records `sep`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::StrRequire;
+    ///
+    /// assert_eq!("[::1]:8080".rsplit_once_required(":"), Ok(("[::1]", "8080")));
+    /// assert!("localhost".rsplit_once_required(":").is_err());
+    /// ```
+    fn rsplit_once_required(&self, sep: &str) -> Result<(&str, &str), NotFoundByKey<Separator, String>>;
+}
+
+impl StrRequire for str {
+    #[inline(always)]
+    fn find_required(&self, pat: &str) -> Result<usize, NotFoundByKey<Substring, String>> {
+        self.find(pat)
+            .ok_or_else(|| NotFoundByKey::new(pat.to_string()))
+    }
+
+    #[inline(always)]
+    fn rfind_required(&self, pat: &str) -> Result<usize, NotFoundByKey<Substring, String>> {
+        self.rfind(pat)
+            .ok_or_else(|| NotFoundByKey::new(pat.to_string()))
+    }
+
+    #[inline(always)]
+    fn strip_prefix_required(&self, prefix: &str) -> Result<&str, NotFoundByKey<Prefix, String>> {
+        self.strip_prefix(prefix)
+            .ok_or_else(|| NotFoundByKey::new(prefix.to_string()))
+    }
+
+    #[inline(always)]
+    fn strip_suffix_required(&self, suffix: &str) -> Result<&str, NotFoundByKey<Suffix, String>> {
+        self.strip_suffix(suffix)
+            .ok_or_else(|| NotFoundByKey::new(suffix.to_string()))
+    }
+
+    #[inline(always)]
+    fn char_at_required(&self, i: usize) -> Result<char, NotFoundByKey<char, usize>> {
+        self.chars().nth(i).ok_or(NotFoundByKey::new(i))
+    }
+
+    #[inline(always)]
+    fn nth_segment_required(&self, sep: &str, n: usize) -> Result<&str, NotFoundByKey<Segment, (String, usize)>> {
+        self.split(sep)
+            .nth(n)
+            .ok_or_else(|| NotFoundByKey::new((sep.to_string(), n)))
+    }
+
+    #[inline(always)]
+    fn split_once_required(&self, sep: &str) -> Result<(&str, &str), NotFoundByKey<Separator, String>> {
+        self.split_once(sep)
+            .ok_or_else(|| NotFoundByKey::new(sep.to_string()))
+    }
+
+    #[inline(always)]
+    fn rsplit_once_required(&self, sep: &str) -> Result<(&str, &str), NotFoundByKey<Separator, String>> {
+        self.rsplit_once(sep)
+            .ok_or_else(|| NotFoundByKey::new(sep.to_string()))
+    }
+}
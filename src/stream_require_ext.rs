@@ -0,0 +1,139 @@
+use std::future::Future;
+use std::pin::pin;
+
+use futures::{Stream, StreamExt};
+
+use crate::report::NotFoundReport;
+use crate::{DynNotFoundError, NotFoundError};
+
+/// An extension trait for streams that returns `NotFoundError` when the stream ends without a match (feature `futures`)
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use futures::stream;
+/// use not_found_error::{NotFoundError, StreamRequireExt};
+///
+/// block_on(async {
+///     let mut events = stream::iter(["connected"]);
+///     assert_eq!(events.next_required().await, Ok("connected"));
+///     assert_eq!(events.next_required().await, Err(NotFoundError::new()));
+/// });
+/// ```
+pub trait StreamRequireExt: Stream {
+    /// Returns the next item, or an error if the stream has ended.
+    fn next_required(&mut self) -> impl Future<Output = Result<Self::Item, NotFoundError<Self::Item>>> + '_
+    where
+        Self: Unpin;
+
+    /// Returns the first item that satisfies the predicate, or an error if the stream ends without a match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream;
+    /// use not_found_error::{NotFoundError, StreamRequireExt};
+    ///
+    /// block_on(async {
+    ///     assert_eq!(stream::iter(1..10).locate(|n| n % 4 == 0).await, Ok(4));
+    ///     assert_eq!(stream::iter(1..3).locate(|n| n % 4 == 0).await, Err(NotFoundError::new()));
+    /// });
+    /// ```
+    fn locate(self, f: impl FnMut(&Self::Item) -> bool) -> impl Future<Output = Result<Self::Item, NotFoundError<Self::Item>>>
+    where
+        Self: Sized;
+
+    /// Returns the first `Some` returned by `f`, or an error if the stream ends without one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream;
+    /// use not_found_error::StreamRequireExt;
+    ///
+    /// let lines = stream::iter(["HTTP/1.1 200 OK", "Content-Length: 42", ""]);
+    /// let length = block_on(lines.locate_map(|line| line.strip_prefix("Content-Length: ")));
+    /// assert_eq!(length, Ok("42"));
+    /// ```
+    fn locate_map<U>(self, f: impl FnMut(Self::Item) -> Option<U>) -> impl Future<Output = Result<U, NotFoundError<U>>>
+    where
+        Self: Sized;
+
+    /// Collects a stream of `Option<T>` into `Vec<T>`, returning a report with the indices of all `None`s.
+    ///
+    /// The async counterpart of [`IteratorExt::require_all_indexed`](crate::IteratorExt::require_all_indexed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::stream;
+    /// use not_found_error::StreamRequireExt;
+    ///
+    /// assert_eq!(block_on(stream::iter([Some(1), Some(2)]).require_all()), Ok(vec![1, 2]));
+    ///
+    /// let report = block_on(stream::iter([None, Some(1), None]).require_all()).unwrap_err();
+    /// assert_eq!(report.to_string(), "2 items not found:\n- i32 not found by key 0\n- i32 not found by key 2");
+    /// ```
+    fn require_all<T>(self) -> impl Future<Output = Result<Vec<T>, NotFoundReport>>
+    where
+        Self: Stream<Item = Option<T>> + Sized;
+}
+
+impl<S: Stream> StreamRequireExt for S {
+    async fn next_required(&mut self) -> Result<S::Item, NotFoundError<S::Item>>
+    where
+        Self: Unpin,
+    {
+        self.next().await.ok_or(NotFoundError::new())
+    }
+
+    async fn locate(self, mut f: impl FnMut(&S::Item) -> bool) -> Result<S::Item, NotFoundError<S::Item>>
+    where
+        Self: Sized,
+    {
+        let mut stream = pin!(self);
+        while let Some(item) = stream.next().await {
+            if f(&item) {
+                return Ok(item);
+            }
+        }
+        Err(NotFoundError::new())
+    }
+
+    async fn locate_map<U>(self, mut f: impl FnMut(S::Item) -> Option<U>) -> Result<U, NotFoundError<U>>
+    where
+        Self: Sized,
+    {
+        let mut stream = pin!(self);
+        while let Some(item) = stream.next().await {
+            if let Some(value) = f(item) {
+                return Ok(value);
+            }
+        }
+        Err(NotFoundError::new())
+    }
+
+    async fn require_all<T>(self) -> Result<Vec<T>, NotFoundReport>
+    where
+        Self: Stream<Item = Option<T>> + Sized,
+    {
+        let mut stream = pin!(self.enumerate());
+        let mut items = Vec::new();
+        let mut report = NotFoundReport::new();
+        while let Some((index, option)) = stream.next().await {
+            match option {
+                Some(item) => items.push(item),
+                None => report.push(DynNotFoundError::with_key::<T>(&index)),
+            }
+        }
+        if report.is_empty() {
+            Ok(items)
+        } else {
+            Err(report)
+        }
+    }
+}
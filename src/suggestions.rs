@@ -0,0 +1,117 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::{mark_not_found, AnyNotFound};
+
+/// The maximum number of suggestions recorded in [`SuggestedNotFoundError`]
+pub const MAX_SUGGESTIONS: usize = 3;
+
+/// Represents an error indicating that a value was not found by key, along with the nearest candidate keys.
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::SuggestedNotFoundError;
+///
+/// let error = SuggestedNotFoundError::<String>::new("--verbos".into(), vec!["--verbose".into()]);
+/// assert_eq!(error.to_string(), "alloc::string::String not found by key \"--verbos\" (did you mean \"--verbose\"?)");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Debug)]
+pub struct SuggestedNotFoundError<T> {
+    pub key: String,
+    /// Candidate keys sorted by edit distance (nearest first)
+    pub suggestions: Vec<String>,
+    pub phantom: PhantomData<T>,
+}
+
+impl<T> SuggestedNotFoundError<T> {
+    /// Creates a new `SuggestedNotFoundError`.
+    pub fn new(key: String, suggestions: Vec<String>) -> Self {
+        Self {
+            key,
+            suggestions,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Display for SuggestedNotFoundError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} not found by key {:?}", type_name::<T>(), self.key)?;
+        if let Some((first, rest)) = self.suggestions.split_first() {
+            write!(f, " (did you mean {first:?}")?;
+            rest.iter()
+                .try_for_each(|suggestion| write!(f, " or {suggestion:?}"))?;
+            write!(f, "?)")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Debug> Error for SuggestedNotFoundError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        mark_not_found(None)
+    }
+}
+
+impl<T> AnyNotFound for SuggestedNotFoundError<T> {
+    fn is_not_found(&self) -> bool {
+        true
+    }
+}
+
+/// Searches an iterator for an element whose key is equal to `key` and returns a reference to it.
+///
+/// If no element matches, the error contains up to [`MAX_SUGGESTIONS`] keys that are close to `key` (by edit distance).
+///
+/// # Examples
+///
+/// ```
+/// # use not_found_error::locate_with_suggestions;
+///
+/// let flags = ["--verbose", "--version", "--quiet"];
+///
+/// assert_eq!(locate_with_suggestions(&flags, "--quiet", |flag| flag), Ok(&"--quiet"));
+///
+/// let error = locate_with_suggestions(&flags, "--verbos", |flag| flag).unwrap_err();
+/// assert_eq!(error.suggestions, vec!["--verbose"]);
+/// ```
+pub fn locate_with_suggestions<'a, T>(iter: impl IntoIterator<Item = &'a T>, key: &str, mut key_fn: impl FnMut(&T) -> &str) -> Result<&'a T, SuggestedNotFoundError<T>> {
+    let threshold = (key.chars().count() / 3).max(1);
+    let mut candidates = Vec::new();
+    for item in iter {
+        let candidate = key_fn(item);
+        if candidate == key {
+            return Ok(item);
+        }
+        let distance = edit_distance(key, candidate);
+        if distance <= threshold {
+            candidates.push((distance, candidate.to_string()));
+        }
+    }
+    candidates.sort();
+    let suggestions = candidates
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate)
+        .collect();
+    Err(SuggestedNotFoundError::new(key.to_string(), suggestions))
+}
+
+/// Returns the Levenshtein distance between `a` and `b` (in chars).
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
@@ -0,0 +1,116 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use crate::{mark_not_found, AnyNotFound, NotFoundError};
+
+/// Represents an error indicating that a value of type `T` was not found within the deadline.
+///
+/// Returned by `within_or_not_found` (feature `tokio`) and `within_or_not_found_async_std` (feature `async-std`).
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use not_found_error::TimedOutNotFound;
+///
+/// let error = TimedOutNotFound::<u32>::new(Duration::from_millis(1500));
+/// assert_eq!(error.to_string(), "u32 not found within 1.5s");
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct TimedOutNotFound<T> {
+    /// The deadline that has elapsed
+    pub duration: Duration,
+    pub phantom: PhantomData<T>,
+}
+
+impl<T> TimedOutNotFound<T> {
+    /// Creates a new `TimedOutNotFound` with the deadline that has elapsed.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Display for TimedOutNotFound<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} not found within {:?}", type_name::<T>(), self.duration)
+    }
+}
+
+impl<T: Debug> Error for TimedOutNotFound<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        mark_not_found(None)
+    }
+}
+
+impl<T> AnyNotFound for TimedOutNotFound<T> {
+    fn is_not_found(&self) -> bool {
+        true
+    }
+}
+
+impl<T> From<TimedOutNotFound<T>> for NotFoundError<T> {
+    fn from(_error: TimedOutNotFound<T>) -> Self {
+        NotFoundError::new()
+    }
+}
+
+/// Awaits the future for at most `duration`, or returns an error that records the deadline if it elapses (feature `tokio`).
+///
+/// It uses [`tokio::time::timeout`], so it must be called within a tokio runtime with the time driver enabled.
+/// Use `within_or_not_found_async_std` (feature `async-std`) on other executors.
+///
+/// # Examples
+///
+/// ```
+/// use std::future::pending;
+/// use std::time::Duration;
+/// use not_found_error::{within_or_not_found, TimedOutNotFound};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let leader = within_or_not_found(Duration::from_millis(10), async { "node-1" }).await;
+/// assert_eq!(leader, Ok("node-1"));
+///
+/// let leader = within_or_not_found(Duration::from_millis(10), pending::<&str>()).await;
+/// assert_eq!(leader, Err(TimedOutNotFound::new(Duration::from_millis(10))));
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn within_or_not_found<T>(duration: Duration, future: impl Future<Output = T>) -> Result<T, TimedOutNotFound<T>> {
+    tokio::time::timeout(duration, future)
+        .await
+        .map_err(|_elapsed| TimedOutNotFound::new(duration))
+}
+
+/// Awaits the future for at most `duration`, or returns an error that records the deadline if it elapses (feature `async-std`).
+///
+/// It uses `async_std::future::timeout`, which works on any executor.
+///
+/// # Examples
+///
+/// ```
+/// use std::future::pending;
+/// use std::time::Duration;
+/// use not_found_error::{within_or_not_found_async_std, TimedOutNotFound};
+///
+/// async_std::task::block_on(async {
+///     let leader = within_or_not_found_async_std(Duration::from_millis(10), async { "node-1" }).await;
+///     assert_eq!(leader, Ok("node-1"));
+///
+///     let leader = within_or_not_found_async_std(Duration::from_millis(10), pending::<&str>()).await;
+///     assert_eq!(leader, Err(TimedOutNotFound::new(Duration::from_millis(10))));
+/// });
+/// ```
+#[cfg(feature = "async-std")]
+pub async fn within_or_not_found_async_std<T>(duration: Duration, future: impl Future<Output = T>) -> Result<T, TimedOutNotFound<T>> {
+    async_std::future::timeout(duration, future)
+        .await
+        .map_err(|_elapsed| TimedOutNotFound::new(duration))
+}
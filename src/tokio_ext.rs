@@ -0,0 +1,90 @@
+use std::future::Future;
+use std::time::Duration;
+
+use tokio::sync::{broadcast, watch};
+
+use crate::{NotFoundError, ReceiveError};
+
+/// An extension trait for [`watch::Receiver<Option<T>>`] that waits for the value to appear (feature `tokio`)
+///
+/// Useful in startup-coordination code that waits for configuration or leadership to be published.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio::sync::watch;
+/// use not_found_error::{NotFoundError, ReceiveError, WatchReceiverExt};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (sender, mut receiver) = watch::channel(None);
+/// let timeout = Duration::from_millis(10);
+/// assert_eq!(receiver.wait_for_some_required(timeout).await, Err(ReceiveError::NotFound(NotFoundError::<String>::new())));
+///
+/// sender.send(Some("node-1".to_string())).unwrap();
+/// assert_eq!(receiver.wait_for_some_required(timeout).await, Ok("node-1".to_string()));
+/// # }
+/// ```
+pub trait WatchReceiverExt<T> {
+    /// Waits until the value is `Some` and returns a clone of it, or returns an error if the timeout elapses or the sender is dropped.
+    fn wait_for_some_required(&mut self, timeout: Duration) -> impl Future<Output = Result<T, ReceiveError<T>>> + Send + '_;
+}
+
+impl<T: Clone + Send + Sync> WatchReceiverExt<T> for watch::Receiver<Option<T>> {
+    async fn wait_for_some_required(&mut self, timeout: Duration) -> Result<T, ReceiveError<T>> {
+        match tokio::time::timeout(timeout, self.wait_for(Option::is_some)).await {
+            Ok(Ok(value)) => value
+                .clone()
+                .ok_or(ReceiveError::NotFound(NotFoundError::new())),
+            Ok(Err(_closed)) => Err(ReceiveError::Disconnected),
+            Err(_elapsed) => Err(ReceiveError::NotFound(NotFoundError::new())),
+        }
+    }
+}
+
+/// An extension trait for [`broadcast::Receiver`] that waits for a matching message (feature `tokio`)
+///
+/// Lagged messages are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio::sync::broadcast;
+/// use not_found_error::{BroadcastReceiverExt, NotFoundError, ReceiveError};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (sender, mut receiver) = broadcast::channel(16);
+/// let timeout = Duration::from_millis(10);
+/// sender.send("follower").unwrap();
+/// sender.send("leader").unwrap();
+/// assert_eq!(receiver.wait_for_required(timeout, |role| *role == "leader").await, Ok("leader"));
+/// assert_eq!(receiver.wait_for_required(timeout, |role| *role == "leader").await, Err(ReceiveError::NotFound(NotFoundError::new())));
+///
+/// drop(sender);
+/// assert_eq!(receiver.wait_for_required(timeout, |_| true).await, Err(ReceiveError::Disconnected));
+/// # }
+/// ```
+pub trait BroadcastReceiverExt<T> {
+    /// Returns the first message that satisfies the predicate, or an error if the timeout elapses or all senders are dropped.
+    fn wait_for_required<F: FnMut(&T) -> bool + Send>(&mut self, timeout: Duration, f: F) -> impl Future<Output = Result<T, ReceiveError<T>>> + Send;
+}
+
+impl<T: Clone + Send> BroadcastReceiverExt<T> for broadcast::Receiver<T> {
+    async fn wait_for_required<F: FnMut(&T) -> bool + Send>(&mut self, timeout: Duration, mut f: F) -> Result<T, ReceiveError<T>> {
+        let wait = async {
+            loop {
+                match self.recv().await {
+                    Ok(message) if f(&message) => return Ok(message),
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return Err(ReceiveError::Disconnected),
+                }
+            }
+        };
+        tokio::time::timeout(timeout, wait)
+            .await
+            .unwrap_or(Err(ReceiveError::NotFound(NotFoundError::new())))
+    }
+}
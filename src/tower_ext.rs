@@ -0,0 +1,118 @@
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use tower::Service;
+
+use crate::{FindAsync, FindError};
+
+/// A boxed future returned by [`FindService`]
+pub type FindServiceFuture<V, E> = Pin<Box<dyn Future<Output = Result<V, FindError<V, E>>> + Send>>;
+
+/// A [`tower::Service`] that looks up keys in a [`FindAsync`] source (feature `tower`)
+///
+/// Responds with the found value, or with [`FindError::NotFound`], so the typed "not found" error survives the middleware stack (timeouts, rate limits, retries).
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use not_found_error::{FindAsync, FindError, FindService};
+/// use tower::ServiceExt;
+///
+/// struct Users;
+///
+/// impl FindAsync<u32> for Users {
+///     type Output = String;
+///     type Error = std::io::Error;
+///
+///     async fn find(&self, id: &u32) -> Result<Option<String>, Self::Error> {
+///         Ok((*id == 1).then(|| "alice".to_string()))
+///     }
+/// }
+///
+/// let service = FindService::new(Users);
+/// assert_eq!(block_on(service.clone().oneshot(1)).unwrap(), "alice");
+/// assert!(matches!(block_on(service.oneshot(2)), Err(FindError::NotFound(_))));
+/// ```
+#[derive(Debug)]
+pub struct FindService<F> {
+    source: Arc<F>,
+}
+
+impl<F> FindService<F> {
+    /// Creates a new `FindService` that looks up keys in `source`.
+    pub fn new(source: F) -> Self {
+        Self {
+            source: Arc::new(source),
+        }
+    }
+}
+
+impl<F> Clone for FindService<F> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+        }
+    }
+}
+
+impl<K: Send + 'static, F: FindAsync<K> + Send + Sync + 'static> Service<K> for FindService<F> {
+    type Response = F::Output;
+    type Error = FindError<F::Output, F::Error>;
+    type Future = FindServiceFuture<F::Output, F::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, key: K) -> Self::Future {
+        let source = self.source.clone();
+        Box::pin(async move { source.find_required(&key).await })
+    }
+}
+
+/// A [`FindAsync`] source backed by a [`tower::Service`] that responds with `Option<V>` (feature `tower`)
+///
+/// Each lookup clones the service and waits for it to become ready, so the service should be cheap to clone (e.g. a `Buffer`).
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use not_found_error::{FindAsync, FindError, ServiceFind};
+/// use tower::service_fn;
+///
+/// let users = ServiceFind::new(service_fn(|id: u32| async move { Ok::<_, std::io::Error>((id == 1).then_some("alice")) }));
+/// assert_eq!(block_on(users.find_required(&1)).unwrap(), "alice");
+/// assert!(matches!(block_on(users.find_required(&2)), Err(FindError::NotFound(_))));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ServiceFind<S> {
+    service: S,
+}
+
+impl<S> ServiceFind<S> {
+    /// Creates a new `ServiceFind` that looks up keys by calling `service`.
+    pub fn new(service: S) -> Self {
+        Self {
+            service,
+        }
+    }
+}
+
+impl<K: Clone + Send + Sync, V, S> FindAsync<K> for ServiceFind<S>
+where
+    S: Service<K, Response = Option<V>> + Clone + Send + Sync,
+    S::Future: Send,
+{
+    type Output = V;
+    type Error = S::Error;
+
+    async fn find(&self, key: &K) -> Result<Option<V>, S::Error> {
+        let mut service = self.service.clone();
+        poll_fn(|cx| service.poll_ready(cx)).await?;
+        service.call(key.clone()).await
+    }
+}
@@ -0,0 +1,68 @@
+use std::fmt::Debug;
+
+use crate::NotFoundError;
+
+/// An extension trait for `Result<T, NotFoundError<M>>` with descriptive panics (useful in tests)
+///
+/// # Examples
+///
+/// ```
+/// use not_found_error::{NotFoundError, Require, UnwrapFound};
+///
+/// assert_eq!(Some(1).require().unwrap_found(), 1);
+/// assert_eq!(None::<i32>.require().unwrap_not_found(), NotFoundError::new());
+/// ```
+///
+/// ```should_panic
+/// use not_found_error::{Require, UnwrapFound};
+///
+/// // Panics with "expected i32 to be found, but got "i32 not found""
+/// None::<i32>.require().unwrap_found();
+/// ```
+///
+/// ```should_panic
+/// use not_found_error::{Require, UnwrapFound};
+///
+/// // Panics with "expected i32 not to be found, but got 1"
+/// Some(1).require().unwrap_not_found();
+/// ```
+pub trait UnwrapFound {
+    type T;
+    type M: ?Sized;
+
+    /// Returns the value, panicking if the result is an error.
+    #[track_caller]
+    fn unwrap_found(self) -> Self::T;
+
+    /// Returns the error, panicking if the result is a value.
+    #[track_caller]
+    fn unwrap_not_found(self) -> NotFoundError<Self::M>
+    where
+        Self::T: Debug;
+}
+
+impl<T, M: ?Sized> UnwrapFound for Result<T, NotFoundError<M>> {
+    type T = T;
+    type M = M;
+
+    #[inline(always)]
+    #[track_caller]
+    fn unwrap_found(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(error) => panic!("expected {} to be found, but got \"{}\"", std::any::type_name::<M>(), error),
+        }
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    fn unwrap_not_found(self) -> NotFoundError<M>
+    where
+        T: Debug,
+    {
+        match self {
+            Ok(value) => panic!("expected {} not to be found, but got {:?}", std::any::type_name::<M>(), value),
+            Err(error) => error,
+        }
+    }
+}
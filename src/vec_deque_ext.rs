@@ -0,0 +1,86 @@
+use std::collections::VecDeque;
+
+use crate::NotFoundError;
+
+/// An extension trait for [`VecDeque`] that returns `NotFoundError<T>` instead of `None` when the deque is empty
+pub trait VecDequeExt {
+    type T;
+
+    /// Returns the front element or an error if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use not_found_error::{NotFoundError, VecDequeExt};
+    ///
+    /// assert_eq!(VecDeque::from([1, 2]).front_required(), Ok(&1));
+    /// assert_eq!(VecDeque::<i32>::new().front_required(), Err(NotFoundError::new()));
+    /// ```
+    fn front_required(&self) -> Result<&Self::T, NotFoundError<Self::T>>;
+
+    /// Returns the back element or an error if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use not_found_error::{NotFoundError, VecDequeExt};
+    ///
+    /// assert_eq!(VecDeque::from([1, 2]).back_required(), Ok(&2));
+    /// assert_eq!(VecDeque::<i32>::new().back_required(), Err(NotFoundError::new()));
+    /// ```
+    fn back_required(&self) -> Result<&Self::T, NotFoundError<Self::T>>;
+
+    /// Removes the front element and returns it, or returns an error if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use not_found_error::{NotFoundError, VecDequeExt};
+    ///
+    /// let mut buffer = VecDeque::from([1]);
+    /// assert_eq!(buffer.pop_front_required(), Ok(1));
+    /// assert_eq!(buffer.pop_front_required(), Err(NotFoundError::new()));
+    /// ```
+    fn pop_front_required(&mut self) -> Result<Self::T, NotFoundError<Self::T>>;
+
+    /// Removes the back element and returns it, or returns an error if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use not_found_error::{NotFoundError, VecDequeExt};
+    ///
+    /// let mut buffer = VecDeque::from([1]);
+    /// assert_eq!(buffer.pop_back_required(), Ok(1));
+    /// assert_eq!(buffer.pop_back_required(), Err(NotFoundError::new()));
+    /// ```
+    fn pop_back_required(&mut self) -> Result<Self::T, NotFoundError<Self::T>>;
+}
+
+impl<T> VecDequeExt for VecDeque<T> {
+    type T = T;
+
+    #[inline(always)]
+    fn front_required(&self) -> Result<&T, NotFoundError<T>> {
+        self.front().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn back_required(&self) -> Result<&T, NotFoundError<T>> {
+        self.back().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn pop_front_required(&mut self) -> Result<T, NotFoundError<T>> {
+        self.pop_front().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn pop_back_required(&mut self) -> Result<T, NotFoundError<T>> {
+        self.pop_back().ok_or(NotFoundError::new())
+    }
+}
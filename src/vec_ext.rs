@@ -0,0 +1,74 @@
+use crate::{NotFoundByKey, NotFoundError};
+
+/// An extension trait for [`Vec`] that returns typed errors instead of panicking or returning `None`
+pub trait VecExt {
+    type T;
+
+    /// Removes the last element and returns it, or returns an error if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundError, VecExt};
+    ///
+    /// let mut stack = vec![1];
+    /// assert_eq!(stack.pop_required(), Ok(1));
+    /// assert_eq!(stack.pop_required(), Err(NotFoundError::new()));
+    /// ```
+    fn pop_required(&mut self) -> Result<Self::T, NotFoundError<Self::T>>;
+
+    /// Removes the element at `index` and returns it, shifting all elements after it to the left, or returns an error that contains the index if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundByKey, VecExt};
+    ///
+    /// let mut queue = vec![1, 2, 3];
+    /// assert_eq!(queue.remove_required(0), Ok(1));
+    /// assert_eq!(queue, vec![2, 3]);
+    /// assert_eq!(queue.remove_required(2), Err(NotFoundByKey::new(2)));
+    /// ```
+    fn remove_required(&mut self, index: usize) -> Result<Self::T, NotFoundByKey<Self::T, usize>>;
+
+    /// Removes the element at `index` and returns it, replacing it with the last element, or returns an error that contains the index if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use not_found_error::{NotFoundByKey, VecExt};
+    ///
+    /// let mut items = vec![1, 2, 3];
+    /// assert_eq!(items.swap_remove_required(0), Ok(1));
+    /// assert_eq!(items, vec![3, 2]);
+    /// assert_eq!(items.swap_remove_required(2), Err(NotFoundByKey::new(2)));
+    /// ```
+    fn swap_remove_required(&mut self, index: usize) -> Result<Self::T, NotFoundByKey<Self::T, usize>>;
+}
+
+impl<T> VecExt for Vec<T> {
+    type T = T;
+
+    #[inline(always)]
+    fn pop_required(&mut self) -> Result<T, NotFoundError<T>> {
+        self.pop().ok_or(NotFoundError::new())
+    }
+
+    #[inline(always)]
+    fn remove_required(&mut self, index: usize) -> Result<T, NotFoundByKey<T, usize>> {
+        if index < self.len() {
+            Ok(self.remove(index))
+        } else {
+            Err(NotFoundByKey::new(index))
+        }
+    }
+
+    #[inline(always)]
+    fn swap_remove_required(&mut self, index: usize) -> Result<T, NotFoundByKey<T, usize>> {
+        if index < self.len() {
+            Ok(self.swap_remove(index))
+        } else {
+            Err(NotFoundByKey::new(index))
+        }
+    }
+}
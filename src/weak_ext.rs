@@ -0,0 +1,48 @@
+use std::{rc, sync};
+
+use crate::NotFoundError;
+
+/// An extension trait for `Weak` pointers that treats a dropped referent as "not found"
+///
+/// Implemented for [`rc::Weak`] and [`sync::Weak`].
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+/// use not_found_error::{NotFoundError, WeakExt};
+///
+/// let session = Rc::new("alice".to_string());
+/// let weak = Rc::downgrade(&session);
+/// assert_eq!(weak.upgrade_required().as_deref(), Ok(&"alice".to_string()));
+///
+/// drop(session);
+/// assert_eq!(weak.upgrade_required(), Err(NotFoundError::<String>::new()));
+/// ```
+pub trait WeakExt {
+    type T: ?Sized;
+    type Strong;
+
+    /// Upgrades the pointer, or returns an error if the referent has been dropped.
+    fn upgrade_required(&self) -> Result<Self::Strong, NotFoundError<Self::T>>;
+}
+
+impl<T: ?Sized> WeakExt for rc::Weak<T> {
+    type T = T;
+    type Strong = rc::Rc<T>;
+
+    #[inline(always)]
+    fn upgrade_required(&self) -> Result<rc::Rc<T>, NotFoundError<T>> {
+        self.upgrade().ok_or(NotFoundError::new())
+    }
+}
+
+impl<T: ?Sized> WeakExt for sync::Weak<T> {
+    type T = T;
+    type Strong = sync::Arc<T>;
+
+    #[inline(always)]
+    fn upgrade_required(&self) -> Result<sync::Arc<T>, NotFoundError<T>> {
+        self.upgrade().ok_or(NotFoundError::new())
+    }
+}